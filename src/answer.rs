@@ -0,0 +1,112 @@
+//! Result of a puzzle part, whatever its shape.
+
+use std::fmt;
+
+use crate::json::Json;
+
+/// Answer of a part.
+///
+/// Integers are kept in an `i64` when they fit, and only widened to a big
+/// integer when they do not, so that a conversion never silently wraps.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+    /// Several lines of text, such as a picture drawn on a grid.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Builds a grid answer from a multi-line string.
+    pub fn grid(s: &str) -> Self {
+        Self::Grid(s.lines().map(str::to_string).collect())
+    }
+
+    /// Value of an integer answer.
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Self::Int(v) => Some(*v as i128),
+            Self::BigInt(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Integers stay numbers, even big ones, and a grid is a list of lines.
+    pub fn to_json(&self) -> Json {
+        match self {
+            Self::Int(v) => Json::from(*v),
+            Self::BigInt(v) => Json::Int(*v),
+            Self::Text(s) => Json::from(s.as_str()),
+            Self::Grid(lines) => {
+                Json::Array(lines.iter().map(|l| Json::from(l.as_str())).collect())
+            }
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(v: i128) -> Self {
+        i64::try_from(v).map_or(Self::BigInt(v), Self::Int)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Self {
+                    Self::from(v as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, i64, u32, u64, usize);
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(v) => write!(f, "{v}"),
+            Self::BigInt(v) => write!(f, "{v}"),
+            Self::Text(s) => f.write_str(s),
+            Self::Grid(lines) => f.write_str(&lines.join("\n")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer() {
+        assert_eq!(Answer::from(42usize), Answer::Int(42));
+        assert_eq!(Answer::from(-3i32), Answer::Int(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(u64::MAX).as_int(), Some(u64::MAX as i128));
+        assert_eq!(Answer::from("ab,cd").as_int(), None);
+
+        let grid = Answer::grid("#.\n.#\n");
+        assert_eq!(grid, Answer::Grid(vec!["#.".to_string(), ".#".to_string()]));
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!(grid.to_json().to_string(), r##"["#.",".#"]"##);
+        assert_eq!(
+            Answer::from(u64::MAX).to_json().to_string(),
+            "18446744073709551615"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Timing of parsing and of each part, for every day.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::input;
+use crate::json::Json;
+use crate::parse::ParseError;
+use crate::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+        }
+    }
+
+    fn to_json(self) -> Json {
+        Json::object([
+            ("min_ns", Json::from(self.min.as_nanos() as u64)),
+            ("median_ns", Json::from(self.median.as_nanos() as u64)),
+        ])
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Timings {
+    pub day: u32,
+    pub title: &'static str,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+fn measure<T>(
+    samples: usize,
+    mut f: impl FnMut() -> Result<T, ParseError>,
+) -> Result<Stats, ParseError> {
+    let mut durations = Vec::with_capacity(samples);
+
+    for _ in 0..samples {
+        let start = Instant::now();
+        black_box(f()?);
+        durations.push(start.elapsed());
+    }
+
+    Ok(Stats::new(durations))
+}
+
+/// Runs parsing and both parts `samples` times each, on the normalized
+/// input. Parts include their own parsing.
+pub fn bench(solution: &dyn Solution, input: &str, samples: usize) -> Result<Timings, ParseError> {
+    assert!(samples > 0);
+    let input = &input::normalize(input);
+
+    Ok(Timings {
+        day: solution.day(),
+        title: solution.title(),
+        parse: measure(samples, || solution.parse(input))?,
+        part1: measure(samples, || solution.part1(input))?,
+        part2: measure(samples, || solution.part2(input))?,
+    })
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+
+    if ns < 1_000 {
+        format!("{ns}ns")
+    } else if ns < 1_000_000 {
+        format!("{:.1}µs", ns as f64 / 1e3)
+    } else if ns < 1_000_000_000 {
+        format!("{:.1}ms", ns as f64 / 1e6)
+    } else {
+        format!("{:.2}s", ns as f64 / 1e9)
+    }
+}
+
+fn format_stats(stats: Stats) -> String {
+    format!(
+        "{} / {}",
+        format_duration(stats.min),
+        format_duration(stats.median)
+    )
+}
+
+/// Formats the timings as a table, one line per day.
+pub fn table(timings: &[Timings]) -> String {
+    let mut lines = vec![format!(
+        "{:>3}  {:<24}  {:>21}  {:>21}  {:>21}",
+        "Day", "Title", "Parse (min / median)", "Part 1 (min / median)", "Part 2 (min / median)"
+    )];
+
+    for t in timings {
+        lines.push(format!(
+            "{:>3}  {:<24}  {:>21}  {:>21}  {:>21}",
+            t.day,
+            t.title,
+            format_stats(t.parse),
+            format_stats(t.part1),
+            format_stats(t.part2)
+        ));
+    }
+
+    lines.join("\n")
+}
+
+pub fn to_json(timings: &[Timings], samples: usize) -> Json {
+    let days = timings
+        .iter()
+        .map(|t| {
+            Json::object([
+                ("day", Json::from(t.day)),
+                ("title", Json::from(t.title)),
+                ("parse", t.parse.to_json()),
+                ("part1", t.part1.to_json()),
+                ("part2", t.part2.to_json()),
+            ])
+        })
+        .collect();
+
+    Json::object([
+        ("samples", Json::from(samples)),
+        ("days", Json::Array(days)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3), ms(9), ms(2)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(ms(1500)), "1.50s");
+
+        assert_eq!(
+            stats.to_json().to_string(),
+            r#"{"min_ns":1000000,"median_ns":3000000}"#
+        );
+    }
+}
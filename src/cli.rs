@@ -0,0 +1,1044 @@
+//! Command line front-end shared by the `aoc` runner and the `dayN` binaries.
+
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::bench;
+use crate::days;
+use crate::fuzz;
+use crate::input::{self, InputSource, Inputs};
+use crate::json::Json;
+use crate::pool;
+use crate::render::{self, ImageFormat, RenderOptions};
+use crate::report::{self, PartReport};
+use crate::template;
+use crate::trace::{self, Level};
+use crate::verify::{self, Answers, Status};
+use crate::visualize::{self, FrameRange, Player, RawTerminal};
+use crate::{Params, Solution};
+
+const USAGE: &str = "Usage:
+    aoc run <day> [--part 1|2] [--input <file>|-|--input-name <name>|--all-inputs]
+                  [--inputs-dir <dir>] [--answers <file>] [--format text|json]
+    aoc run --all [--part 1|2] [--input-name <name>|--all-inputs] [--inputs-dir <dir>]
+                  [--answers <file>] [--format text|json] [--jobs <n>]
+    aoc bench [<day>] [--samples <n>] [--json <file>] [--inputs-dir <dir>]
+    aoc verify [<day>] [--answers <file>] [--inputs-dir <dir>]
+    aoc fuzz [<day>] [--iterations <n>] [--seed <n>] [--timeout <ms>] [--solve]
+                  [--inputs-dir <dir>]
+    aoc visualize <day> [--input <file>] [--inputs-dir <dir>] [--fps <n>] [--frames <start>..<end>]
+                  [--render <dir> [--image ppm|pgm|gif] [--scale <n>]]
+    aoc new <day> [--title <title>]
+
+Options:
+    -v, -vv    Trace the solvers to stderr, -vv tracing every step
+    --solve    Fuzzes the parts too, which are otherwise out of scope as the slow
+               ones would time out
+    --render   Exports at most 500 frames, unless --frames has an end";
+
+const DAY_USAGE: &str = "[-v|-vv] [--part 1|2] [--input <file>|-|--input-name <name>|--all-inputs]
+    [--inputs-dir <dir>] [--answers <file>] [--format text|json]";
+
+enum Selection {
+    Day(u32),
+    All,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    /// Parses the value of `--format`.
+    pub(crate) fn from_arg(value: Option<String>) -> Result<Self, String> {
+        let value = value.ok_or("--format expects a value")?;
+
+        match value.as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Invalid format '{value}'")),
+        }
+    }
+}
+
+struct RunArgs {
+    selection: Selection,
+    part: Option<u32>,
+    input: Option<InputSource>,
+    input_name: Option<String>,
+    all_inputs: bool,
+    inputs: Inputs,
+    answers: Option<PathBuf>,
+    format: Format,
+    jobs: Option<usize>,
+}
+
+fn parse_run_args(
+    mut args: impl Iterator<Item = String>,
+    mut selection: Option<Selection>,
+) -> Result<RunArgs, String> {
+    let allow_selection = selection.is_none();
+    let mut part = None;
+    let mut input = None;
+    let mut input_name = None;
+    let mut all_inputs = false;
+    let mut inputs = None;
+    let mut answers = None;
+    let mut format = Format::Text;
+    let mut jobs = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" if allow_selection => selection = Some(Selection::All),
+            "--part" => {
+                let value = args.next().ok_or("--part expects a value")?;
+                match value.as_str() {
+                    "1" => part = Some(1),
+                    "2" => part = Some(2),
+                    _ => return Err(format!("Invalid part '{value}'")),
+                }
+            }
+            "--input" => {
+                let value = args.next().ok_or("--input expects a file or '-'")?;
+                input = Some(InputSource::from_arg(&value));
+            }
+            "-" => input = Some(InputSource::Stdin),
+            "--input-name" => {
+                let value = args.next().ok_or("--input-name expects a name")?;
+                input_name = Some(value);
+            }
+            "--all-inputs" => all_inputs = true,
+            "--inputs-dir" => {
+                let value = args.next().ok_or("--inputs-dir expects a directory")?;
+                inputs = Some(Inputs::new(value));
+            }
+            "--answers" => {
+                let value = args.next().ok_or("--answers expects a file")?;
+                answers = Some(value.into());
+            }
+            "--format" => format = Format::from_arg(args.next())?,
+            "--jobs" | "-j" => {
+                let value = args.next().ok_or("--jobs expects a value")?;
+                let n = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("Invalid number of jobs '{value}'"))?;
+                jobs = Some(n);
+            }
+            _ if allow_selection => {
+                let day = arg
+                    .parse()
+                    .map_err(|_| format!("Unexpected argument '{arg}'"))?;
+                selection = Some(Selection::Day(day));
+            }
+            _ => return Err(format!("Unexpected argument '{arg}'")),
+        }
+    }
+
+    let selection = selection.ok_or("Missing day")?;
+
+    if input.is_some() && matches!(selection, Selection::All) {
+        return Err("--input cannot be used with --all".to_string());
+    }
+
+    if input.is_some() && (input_name.is_some() || all_inputs) {
+        return Err("--input cannot be used with --input-name or --all-inputs".to_string());
+    }
+
+    if input_name.is_some() && all_inputs {
+        return Err("--input-name cannot be used with --all-inputs".to_string());
+    }
+
+    if jobs.is_some() && !matches!(selection, Selection::All) {
+        return Err("--jobs can only be used with --all".to_string());
+    }
+
+    Ok(RunArgs {
+        selection,
+        part,
+        input,
+        input_name,
+        all_inputs,
+        inputs: inputs.unwrap_or_else(Inputs::from_env),
+        answers,
+        format,
+        jobs,
+    })
+}
+
+/// An input of a day, with its name when it comes from the inputs directory.
+struct DayInput {
+    name: Option<String>,
+    text: String,
+}
+
+/// Reads the inputs selected for a day: the given file, a named input, or
+/// every named input with `--all-inputs`.
+fn day_inputs(args: &RunArgs, day: u32) -> Result<Vec<DayInput>, String> {
+    if let Some(source) = &args.input {
+        let text = input::read(source).map_err(|e| e.to_string())?;
+        return Ok(vec![DayInput { name: None, text }]);
+    }
+
+    let names = if args.all_inputs {
+        let names = args.inputs.names(day);
+        if names.is_empty() {
+            return Err(format!(
+                "No inputs for day {day} in '{}'",
+                args.inputs.dir().display()
+            ));
+        }
+        names
+    } else {
+        let name = args.input_name.as_deref();
+        vec![name.unwrap_or(input::DEFAULT_INPUT_NAME).to_string()]
+    };
+
+    names
+        .into_iter()
+        .map(|name| {
+            let text = args
+                .inputs
+                .read_named(day, &name)
+                .map_err(|e| e.to_string())?;
+            Ok(DayInput {
+                name: Some(name),
+                text,
+            })
+        })
+        .collect()
+}
+
+/// Loads the answers given with `--answers`, or the default answers file if
+/// there is one.
+fn load_answers(path: Option<&Path>) -> Result<Answers, String> {
+    match path {
+        Some(path) => Answers::load(path),
+        None => {
+            let path = Path::new(verify::DEFAULT_ANSWERS_FILE);
+            if path.exists() {
+                Answers::load(path)
+            } else {
+                Ok(Answers::default())
+            }
+        }
+    }
+}
+
+pub(crate) fn print_header(solution: &dyn Solution, name: Option<&str>) {
+    match name {
+        Some(name) if name != input::DEFAULT_INPUT_NAME => {
+            println!("Day {}: {} ({name})", solution.day(), solution.title())
+        }
+        _ => println!("Day {}: {}", solution.day(), solution.title()),
+    }
+}
+
+/// Prints an answer, along with how it compares to the expected one when
+/// that is known, and the time it took when given.
+pub(crate) fn print_answer(
+    part: u32,
+    answer: &Answer,
+    expected: Option<&str>,
+    elapsed: Option<Duration>,
+) {
+    let mut check = match expected {
+        Some(expected) if expected == answer.to_string() => "  [ok]".to_string(),
+        Some(expected) => format!("  [expected {expected}]"),
+        None => String::new(),
+    };
+    if let Some(elapsed) = elapsed {
+        check += &format!("  ({})", bench::format_duration(elapsed));
+    }
+
+    match answer {
+        // A grid starts on its own line so that it stays aligned
+        Answer::Grid(_) => println!("Part {part}:{check}\n{answer}"),
+        _ => println!("Part {part}: {answer}{check}"),
+    }
+}
+
+/// A report along with the name of its input and the expected answer.
+fn report_json(report: &PartReport, name: Option<&str>, expected: Option<&str>) -> Json {
+    let mut json = report.to_json();
+    if let Json::Object(entries) = &mut json {
+        entries.push(("input".to_string(), Json::from(name)));
+        let expected = expected.map(|e| e.parse().map_or(Json::from(e), Json::Int));
+        entries.push(("expected".to_string(), expected.unwrap_or(Json::Null)));
+    }
+
+    json
+}
+
+/// Runs the selected parts of a day on an input. Answers are printed as they
+/// come in text format, and only returned in JSON format.
+fn run_day(
+    solution: &dyn Solution,
+    part: Option<u32>,
+    input: &DayInput,
+    answers: &Answers,
+    format: Format,
+) -> Result<Vec<Json>, String> {
+    let name = input.name.as_deref();
+
+    if format == Format::Text {
+        print_header(solution, name);
+    }
+
+    let mut reports = vec![];
+
+    for p in [1, 2] {
+        if part.is_some_and(|part| part != p) {
+            continue;
+        }
+
+        let params = name.map_or_else(Params::new, |name| answers.params(solution.day(), name));
+        let r = report::run_part(solution, p, &input.text, &params).map_err(|e| e.to_string())?;
+        let expected = name.and_then(|name| answers.get(solution.day(), name, p));
+
+        match format {
+            Format::Text => print_answer(p, &r.answer, expected, None),
+            Format::Json => reports.push(report_json(&r, name, expected)),
+        }
+    }
+
+    Ok(reports)
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let answers = load_answers(args.answers.as_deref())?;
+
+    match args.selection {
+        Selection::Day(day) => {
+            let solution = days::get(day).ok_or(format!("Day {day} is not solved"))?;
+
+            for (idx, input) in day_inputs(&args, day)?.iter().enumerate() {
+                if idx > 0 && args.format == Format::Text {
+                    println!();
+                }
+
+                let reports = run_day(solution, args.part, input, &answers, args.format)?;
+
+                // One document per part
+                for r in reports {
+                    println!("{r}");
+                }
+            }
+        }
+        Selection::All => run_all(&args, &answers)?,
+    }
+
+    Ok(())
+}
+
+/// Runs every part of every day on a pool of threads, and prints the
+/// answers in day order once they are all known.
+fn run_all(args: &RunArgs, answers: &Answers) -> Result<(), String> {
+    let jobs = args.jobs.unwrap_or_else(pool::default_threads);
+
+    let inputs = days::DAYS
+        .iter()
+        .map(|&s| Ok((s, day_inputs(args, s.day())?)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let tasks: Vec<(&dyn Solution, &DayInput, u32)> = inputs
+        .iter()
+        .flat_map(|(solution, inputs)| inputs.iter().map(move |input| (*solution, input)))
+        .flat_map(|(solution, input)| {
+            [1, 2]
+                .into_iter()
+                .filter(|&p| args.part.is_none() || args.part == Some(p))
+                .map(move |p| (solution, input, p))
+        })
+        .collect();
+
+    let start = Instant::now();
+    // A panicking solver fails its part only, the others still run
+    let results = pool::map(&tasks, jobs, |&(solution, input, part)| {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            let name = input.name.as_deref();
+            let params = name.map_or_else(Params::new, |name| answers.params(solution.day(), name));
+            report::run_part(solution, part, &input.text, &params)
+        }))
+        .map_err(|payload| format!("panicked: {}", fuzz::panic_message(payload)))
+        .and_then(|r| r.map_err(|e| e.to_string()))
+    });
+    let wall = start.elapsed();
+
+    let text = args.format == Format::Text;
+    let mut reports = vec![];
+    let mut summed = Duration::ZERO;
+    let mut failed = 0;
+    let mut last = None;
+
+    for (&(solution, input, part), result) in tasks.iter().zip(results) {
+        let name = input.name.as_deref();
+
+        if text && last != Some((solution.day(), name)) {
+            if last.is_some() {
+                println!();
+            }
+            print_header(solution, name);
+            last = Some((solution.day(), name));
+        }
+
+        match result {
+            Ok(r) => {
+                let expected = name.and_then(|name| answers.get(solution.day(), name, part));
+                match args.format {
+                    Format::Text => print_answer(part, &r.answer, expected, Some(r.elapsed)),
+                    Format::Json => reports.push(report_json(&r, name, expected)),
+                }
+                summed += r.elapsed;
+            }
+            Err(e) => {
+                eprintln!("Day {} part {part}: {e}", solution.day());
+                failed += 1;
+            }
+        }
+    }
+
+    match args.format {
+        Format::Json => println!("{}", Json::Array(reports)),
+        Format::Text => {
+            println!();
+            println!(
+                "{} parts on {jobs} {}: {} wall-clock, {} summed over the parts",
+                tasks.len(),
+                if jobs == 1 { "thread" } else { "threads" },
+                bench::format_duration(wall),
+                bench::format_duration(summed)
+            );
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{failed} parts failed"));
+    }
+
+    Ok(())
+}
+
+struct BenchArgs {
+    day: Option<u32>,
+    samples: usize,
+    json: Option<PathBuf>,
+    inputs: Inputs,
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut day = None;
+    let mut samples = 10;
+    let mut json = None;
+    let mut inputs = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--samples" => {
+                let value = args.next().ok_or("--samples expects a value")?;
+                samples = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("Invalid sample count '{value}'"))?;
+            }
+            "--json" => {
+                let value = args.next().ok_or("--json expects a file")?;
+                json = Some(value.into());
+            }
+            "--inputs-dir" => {
+                let value = args.next().ok_or("--inputs-dir expects a directory")?;
+                inputs = Some(Inputs::new(value));
+            }
+            _ => {
+                let value = arg
+                    .parse()
+                    .map_err(|_| format!("Unexpected argument '{arg}'"))?;
+                day = Some(value);
+            }
+        }
+    }
+
+    Ok(BenchArgs {
+        day,
+        samples,
+        json,
+        inputs: inputs.unwrap_or_else(Inputs::from_env),
+    })
+}
+
+fn run_bench(args: BenchArgs) -> Result<(), String> {
+    let solutions = match args.day {
+        Some(day) => vec![days::get(day).ok_or(format!("Day {day} is not solved"))?],
+        None => days::DAYS.to_vec(),
+    };
+
+    if cfg!(debug_assertions) {
+        eprintln!("Warning: benchmarking a debug build, use --release for meaningful timings");
+    }
+
+    let mut timings = vec![];
+
+    for solution in solutions {
+        let input = args
+            .inputs
+            .read(solution.day())
+            .map_err(|e| e.to_string())?;
+        let t = bench::bench(solution, &input, args.samples).map_err(|e| e.to_string())?;
+        timings.push(t);
+    }
+
+    println!("{}", bench::table(&timings));
+
+    if let Some(path) = args.json {
+        let report = bench::to_json(&timings, args.samples);
+        std::fs::write(&path, format!("{report}\n"))
+            .map_err(|e| format!("Cannot write '{}': {e}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+struct VerifyArgs {
+    day: Option<u32>,
+    answers: PathBuf,
+    inputs: Inputs,
+}
+
+fn parse_verify_args(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs, String> {
+    let mut day = None;
+    let mut answers = PathBuf::from(verify::DEFAULT_ANSWERS_FILE);
+    let mut inputs = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                let value = args.next().ok_or("--answers expects a file")?;
+                answers = value.into();
+            }
+            "--inputs-dir" => {
+                let value = args.next().ok_or("--inputs-dir expects a directory")?;
+                inputs = Some(Inputs::new(value));
+            }
+            _ => {
+                let value = arg
+                    .parse()
+                    .map_err(|_| format!("Unexpected argument '{arg}'"))?;
+                day = Some(value);
+            }
+        }
+    }
+
+    Ok(VerifyArgs {
+        day,
+        answers,
+        inputs: inputs.unwrap_or_else(Inputs::from_env),
+    })
+}
+
+fn run_verify(args: VerifyArgs) -> Result<(), String> {
+    let answers = Answers::load(&args.answers)?;
+
+    let selected_days = match args.day {
+        Some(day) => vec![day],
+        None => {
+            let mut all = answers.days();
+            all.extend(days::DAYS.iter().map(|s| s.day()));
+            all.into_iter().collect()
+        }
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in selected_days {
+        for check in verify::verify_day(day, days::get(day), &answers, &args.inputs) {
+            match check.status {
+                Status::Pass => passed += 1,
+                Status::Fail { .. } | Status::Error(_) => failed += 1,
+                Status::Missing(_) => missing += 1,
+            }
+
+            println!("{check}");
+        }
+    }
+
+    println!();
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if failed > 0 {
+        return Err(format!("Verification failed for {failed} checks"));
+    }
+
+    Ok(())
+}
+
+struct FuzzArgs {
+    day: Option<u32>,
+    iterations: usize,
+    seed: u64,
+    timeout: Duration,
+    solve: bool,
+    inputs: Inputs,
+}
+
+fn parse_fuzz_args(mut args: impl Iterator<Item = String>) -> Result<FuzzArgs, String> {
+    let mut day = None;
+    let mut iterations = 10_000;
+    let mut seed = 0;
+    let mut timeout = Duration::from_secs(1);
+    let mut solve = false;
+    let mut inputs = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let value = args.next().ok_or("--iterations expects a value")?;
+                iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("Invalid number of iterations '{value}'"))?;
+            }
+            "--seed" => {
+                let value = args.next().ok_or("--seed expects a value")?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("Invalid seed '{value}'"))?;
+            }
+            "--timeout" => {
+                let value = args.next().ok_or("--timeout expects milliseconds")?;
+                timeout = value
+                    .parse()
+                    .ok()
+                    .filter(|&ms| ms > 0)
+                    .map(Duration::from_millis)
+                    .ok_or(format!("Invalid timeout '{value}'"))?;
+            }
+            "--solve" => solve = true,
+            "--inputs-dir" => {
+                let value = args.next().ok_or("--inputs-dir expects a directory")?;
+                inputs = Some(Inputs::new(value));
+            }
+            _ => {
+                let value = arg
+                    .parse()
+                    .map_err(|_| format!("Unexpected argument '{arg}'"))?;
+                day = Some(value);
+            }
+        }
+    }
+
+    Ok(FuzzArgs {
+        day,
+        iterations,
+        seed,
+        timeout,
+        solve,
+        inputs: inputs.unwrap_or_else(Inputs::from_env),
+    })
+}
+
+fn run_fuzz(args: FuzzArgs) -> Result<(), String> {
+    let solutions = match args.day {
+        Some(day) => vec![days::get(day).ok_or(format!("Day {day} is not solved"))?],
+        None => days::DAYS.to_vec(),
+    };
+
+    // Panics are reported with their input instead
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut findings = 0;
+
+    for (idx, &solution) in solutions.iter().enumerate() {
+        let day = solution.day();
+        let corpus = args
+            .inputs
+            .names(day)
+            .iter()
+            .filter_map(|name| args.inputs.read_named(day, name).ok())
+            .collect();
+
+        let summary = fuzz::fuzz(
+            solution,
+            corpus,
+            args.seed ^ day as u64,
+            args.iterations,
+            args.timeout,
+            args.solve,
+        );
+
+        println!(
+            "Day {day:>2}  {} accepted, {} rejected, {} findings",
+            summary.accepted,
+            summary.rejected,
+            summary.findings.len()
+        );
+
+        for finding in &summary.findings {
+            match &finding.outcome {
+                fuzz::Outcome::TimedOut => println!("    hangs on {:?}", finding.input),
+                fuzz::Outcome::Panicked(message) => {
+                    println!("    panics with '{message}' on {:?}", finding.input)
+                }
+                _ => {}
+            }
+        }
+
+        findings += summary.findings.len();
+
+        // A hung parser or part keeps running on its thread, and would slow down the
+        // next days enough to time them out too
+        if summary
+            .findings
+            .iter()
+            .any(|f| f.outcome == fuzz::Outcome::TimedOut)
+        {
+            let skipped: Vec<String> = solutions[idx + 1..]
+                .iter()
+                .map(|s| s.day().to_string())
+                .collect();
+            if !skipped.is_empty() {
+                println!("Day {day} hangs, not fuzzing days {}", skipped.join(", "));
+            }
+            break;
+        }
+    }
+
+    let _ = std::panic::take_hook();
+
+    if findings > 0 {
+        return Err(format!("{findings} inputs made a parser panic or hang"));
+    }
+
+    Ok(())
+}
+
+/// Input options of the subcommands of the `dayN` binaries.
+pub(crate) struct InputArgs {
+    pub input: Option<InputSource>,
+    pub input_name: Option<String>,
+    pub inputs: Inputs,
+}
+
+impl InputArgs {
+    /// Parses `--input`, `-`, `--input-name` and `--inputs-dir`, handing any
+    /// other argument to `other` along with the remaining ones, from which it
+    /// takes its value.
+    pub(crate) fn parse<I: Iterator<Item = String>>(
+        mut args: I,
+        mut other: impl FnMut(&str, &mut I) -> Result<(), String>,
+    ) -> Result<Self, String> {
+        let mut input = None;
+        let mut input_name = None;
+        let mut inputs = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => {
+                    let value = args.next().ok_or("--input expects a file or '-'")?;
+                    input = Some(InputSource::from_arg(&value));
+                }
+                "-" => input = Some(InputSource::Stdin),
+                "--input-name" => {
+                    let value = args.next().ok_or("--input-name expects a name")?;
+                    input_name = Some(value);
+                }
+                "--inputs-dir" => {
+                    let value = args.next().ok_or("--inputs-dir expects a directory")?;
+                    inputs = Some(Inputs::new(value));
+                }
+                _ => other(&arg, &mut args)?,
+            }
+        }
+
+        if input.is_some() && input_name.is_some() {
+            return Err("--input cannot be used with --input-name".to_string());
+        }
+
+        Ok(Self {
+            input,
+            input_name,
+            inputs: inputs.unwrap_or_else(Inputs::from_env),
+        })
+    }
+
+    /// Where the input of `day` is read from.
+    pub(crate) fn source(&self, day: u32) -> InputSource {
+        match (&self.input, &self.input_name) {
+            (Some(source), _) => source.clone(),
+            (None, Some(name)) => InputSource::File(self.inputs.named_path(day, name)),
+            (None, None) => InputSource::File(self.inputs.path(day)),
+        }
+    }
+
+    /// Reads the input of `day`.
+    pub(crate) fn read(&self, day: u32) -> Result<String, String> {
+        match (&self.input, &self.input_name) {
+            (Some(source), _) => input::read(source),
+            (None, Some(name)) => self.inputs.read_named(day, name),
+            (None, None) => self.inputs.read(day),
+        }
+        .map_err(|e| e.to_string())
+    }
+}
+
+struct VisualizeArgs {
+    day: u32,
+    input: Option<PathBuf>,
+    inputs: Inputs,
+    fps: f64,
+    frames: FrameRange,
+    render: Option<PathBuf>,
+    image: ImageFormat,
+    scale: usize,
+}
+
+fn parse_visualize_args(mut args: impl Iterator<Item = String>) -> Result<VisualizeArgs, String> {
+    let mut day = None;
+    let mut input = None;
+    let mut inputs = None;
+    let mut fps = 10.0;
+    let mut frames = FrameRange::default();
+    let mut render = None;
+    let mut image = ImageFormat::Ppm;
+    let mut scale = 4;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let value = args.next().ok_or("--input expects a file")?;
+                input = Some(value.into());
+            }
+            "--inputs-dir" => {
+                let value = args.next().ok_or("--inputs-dir expects a directory")?;
+                inputs = Some(Inputs::new(value));
+            }
+            "--fps" => {
+                let value = args.next().ok_or("--fps expects a value")?;
+                fps = value
+                    .parse()
+                    .ok()
+                    .filter(|&fps: &f64| fps > 0.0 && fps.is_finite())
+                    .ok_or(format!("Invalid frame rate '{value}'"))?;
+            }
+            "--frames" => {
+                let value = args.next().ok_or("--frames expects a range")?;
+                frames = FrameRange::parse(&value).ok_or(format!(
+                    "Invalid frame range '{value}', expected <start>..<end>"
+                ))?;
+            }
+            "--render" => {
+                let value = args.next().ok_or("--render expects a directory")?;
+                render = Some(value.into());
+            }
+            "--image" => {
+                let value = args.next().ok_or("--image expects a format")?;
+                image =
+                    ImageFormat::parse(&value).ok_or(format!("Invalid image format '{value}'"))?;
+            }
+            "--scale" => {
+                let value = args.next().ok_or("--scale expects a value")?;
+                scale = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("Invalid scale '{value}'"))?;
+            }
+            _ => {
+                let value = arg
+                    .parse()
+                    .map_err(|_| format!("Unexpected argument '{arg}'"))?;
+                day = Some(value);
+            }
+        }
+    }
+
+    Ok(VisualizeArgs {
+        day: day.ok_or("Missing day")?,
+        input,
+        inputs: inputs.unwrap_or_else(Inputs::from_env),
+        fps,
+        frames,
+        render,
+        image,
+        scale,
+    })
+}
+
+/// Frames exported when `--frames` has no end.
+const MAX_RENDERED_FRAMES: usize = 500;
+
+fn run_visualize(args: VisualizeArgs) -> Result<(), String> {
+    let solution = days::get(args.day).ok_or(format!("Day {} is not solved", args.day))?;
+    let visualization = solution
+        .visualize()
+        .ok_or(format!("Day {} has no visualization", args.day))?;
+
+    // Stdin is kept for the controls
+    let input = match args.input {
+        Some(path) => input::read(&InputSource::File(path)),
+        None => args.inputs.read(args.day),
+    }
+    .map_err(|e| e.to_string())?;
+
+    let frames = visualization.frames(&input).map_err(|e| e.to_string())?;
+
+    if let Some(dir) = args.render {
+        let options = RenderOptions {
+            format: args.image,
+            scale: args.scale,
+            fps: args.fps,
+        };
+        let color = |c| visualization.color(c);
+
+        // Simulations can be very long or never end, so that only a limited
+        // number of frames is exported unless the range has an end. One more
+        // frame is selected to tell whether some were cut.
+        let (range, cap) = match args.frames.end {
+            Some(_) => (args.frames, None),
+            None => {
+                let cap = args.frames.start + MAX_RENDERED_FRAMES;
+                let range = FrameRange {
+                    end: Some(cap + 1),
+                    ..args.frames
+                };
+                (range, Some(cap))
+            }
+        };
+        let cut = Cell::new(false);
+        let selected = range.select(frames).filter(|(idx, _)| match cap {
+            Some(cap) if *idx >= cap => {
+                cut.set(true);
+                false
+            }
+            _ => true,
+        });
+
+        let written = render::export(
+            selected,
+            &color,
+            &options,
+            &dir,
+            &format!("day{:02}", args.day),
+        )
+        .map_err(|e| format!("Cannot render to '{}': {e}", dir.display()))?;
+
+        println!("Wrote {} files to {}", written.len(), dir.display());
+        if cut.get() {
+            println!(
+                "Stopped after {MAX_RENDERED_FRAMES} frames, select others with --frames <start>..<end>"
+            );
+        }
+        return Ok(());
+    }
+
+    let _terminal = RawTerminal::enable();
+    let controls = visualize::stdin_controls();
+
+    Player::new(args.fps)
+        .play(
+            args.frames.select(frames),
+            &controls,
+            &mut std::io::stdout().lock(),
+        )
+        .map_err(|e| format!("Cannot write the frames: {e}"))
+}
+
+fn run_new(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut day = None;
+    let mut title = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" => title = Some(args.next().ok_or("--title expects a value")?),
+            _ => {
+                let value: u32 = arg
+                    .parse()
+                    .map_err(|_| format!("Unexpected argument '{arg}'"))?;
+                day = Some(value);
+            }
+        }
+    }
+
+    let day = day
+        .filter(|d| (1..=25).contains(d))
+        .ok_or("Expected a day between 1 and 25")?;
+    let title = title.unwrap_or_else(|| format!("Day {day}"));
+
+    for path in template::scaffold(Path::new("."), day, &title)? {
+        println!("Created {}", path.display());
+    }
+
+    Ok(())
+}
+
+pub(crate) fn exit(r: Result<(), String>) -> ExitCode {
+    match r {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Takes the verbosity flags out of the arguments, wherever they are, and
+/// sets the tracing level accordingly.
+pub(crate) fn args() -> impl Iterator<Item = String> {
+    let mut level = None;
+    let mut args = vec![];
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "-v" => level = level.max(Some(Level::Info)),
+            "-vv" => level = Some(Level::Debug),
+            _ => args.push(arg),
+        }
+    }
+
+    trace::set_level(level);
+
+    args.into_iter()
+}
+
+/// Entry point of the `aoc` runner.
+pub fn main() -> ExitCode {
+    let mut args = args();
+
+    let r = match args.next().as_deref() {
+        Some("run") => parse_run_args(args, None).and_then(run),
+        Some("bench") => parse_bench_args(args).and_then(run_bench),
+        Some("verify") => parse_verify_args(args).and_then(run_verify),
+        Some("fuzz") => parse_fuzz_args(args).and_then(run_fuzz),
+        Some("visualize") => parse_visualize_args(args).and_then(run_visualize),
+        Some("new") => run_new(args),
+        _ => Err(USAGE.to_string()),
+    };
+
+    exit(r)
+}
+
+/// Entry point of a `dayN` binary.
+pub fn day_main(solution: &dyn Solution) -> ExitCode {
+    run_day_binary(solution, args())
+}
+
+pub(crate) fn run_day_binary(
+    solution: &dyn Solution,
+    args: impl Iterator<Item = String>,
+) -> ExitCode {
+    let selection = Some(Selection::Day(solution.day()));
+
+    let r = parse_run_args(args, selection)
+        .map_err(|e| format!("{e}\nUsage: day{} {DAY_USAGE}", solution.day()))
+        .and_then(run);
+
+    exit(r)
+}
@@ -0,0 +1,239 @@
+//! Subcommands of the `day1` binary.
+
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process::ExitCode;
+
+use super::compare::{self, Metric, Pairing};
+use super::diff::{self, Diff};
+use super::stream;
+use crate::cli::{self, Format, InputArgs};
+use crate::input::InputSource;
+
+const COMPARE_USAGE: &str =
+    "Usage: day1 compare [--input <file>|-|--input-name <name>] [--inputs-dir <dir>]
+                    [--reference <column>] [--metric <name>]... [--format text|json]
+
+Compares the columns two at a time, every pair of them or each one against a
+reference column, numbered from 1. Metrics: sorted-l1, sorted-l2, spearman,
+intersection and similarity, all of them by default.";
+
+struct CompareArgs {
+    input: InputArgs,
+    reference: Option<usize>,
+    metrics: Vec<Metric>,
+    format: Format,
+}
+
+fn parse_compare_args(args: impl Iterator<Item = String>) -> Result<CompareArgs, String> {
+    let mut reference = None;
+    let mut metrics = vec![];
+    let mut format = Format::Text;
+
+    let input = InputArgs::parse(args, |arg, args| {
+        match arg {
+            "--reference" => {
+                let value = args.next().ok_or("--reference expects a column")?;
+                let column = value
+                    .parse()
+                    .ok()
+                    .filter(|&c| c > 0)
+                    .ok_or(format!("Invalid column '{value}'"))?;
+                reference = Some(column);
+            }
+            "--metric" => {
+                let value = args.next().ok_or("--metric expects a name")?;
+                metrics.push(Metric::parse(&value).ok_or(format!("Unknown metric '{value}'"))?);
+            }
+            "--format" => format = Format::from_arg(args.next())?,
+            _ => return Err(format!("Unexpected argument '{arg}'")),
+        }
+
+        Ok(())
+    })?;
+
+    if metrics.is_empty() {
+        metrics = Metric::ALL.to_vec();
+    }
+
+    Ok(CompareArgs {
+        input,
+        reference,
+        metrics,
+        format,
+    })
+}
+
+fn run_compare(args: CompareArgs) -> Result<(), String> {
+    let input = args.input.read(1)?;
+
+    let columns = super::parse_columns(&input).map_err(|e| e.to_string())?;
+
+    let pairing = match args.reference {
+        Some(column) if column > columns.len() => {
+            return Err(format!(
+                "No column {column}, the input has {}",
+                columns.len()
+            ))
+        }
+        Some(column) => Pairing::Reference(column - 1),
+        None => Pairing::All,
+    };
+
+    let reports = compare::compare(&columns, pairing, &args.metrics);
+
+    match args.format {
+        Format::Text => println!("{}", compare::table(&reports, &args.metrics)),
+        Format::Json => println!("{}", compare::to_json(&reports)),
+    }
+
+    Ok(())
+}
+
+const DIFF_USAGE: &str =
+    "Usage: day1 diff [--input <file>|-|--input-name <name>] [--inputs-dir <dir>]
+                 [--top <k>] [--format text|json]
+
+Explains the answers: the sorted pairs and their distances, the --top largest
+of them (10 by default), the values found in a single list, and the count of
+each value in both lists along with its part of the similarity score.";
+
+struct DiffArgs {
+    input: InputArgs,
+    top: usize,
+    format: Format,
+}
+
+fn parse_diff_args(args: impl Iterator<Item = String>) -> Result<DiffArgs, String> {
+    let mut top = 10;
+    let mut format = Format::Text;
+
+    let input = InputArgs::parse(args, |arg, args| {
+        match arg {
+            "--top" => {
+                let value = args.next().ok_or("--top expects a number")?;
+                top = value
+                    .parse()
+                    .map_err(|_| format!("Invalid --top '{value}'"))?;
+            }
+            "--format" => format = Format::from_arg(args.next())?,
+            _ => return Err(format!("Unexpected argument '{arg}'")),
+        }
+
+        Ok(())
+    })?;
+
+    Ok(DiffArgs { input, top, format })
+}
+
+fn run_diff(args: DiffArgs) -> Result<(), String> {
+    let input = args.input.read(1)?;
+    let (left, right) = super::parse_input(&input).map_err(|e| e.to_string())?;
+    let diff = Diff::new(&left, &right);
+
+    match args.format {
+        Format::Text => println!("{}", diff::text(&diff, args.top)),
+        Format::Json => println!("{}", diff::to_json(&diff, args.top)),
+    }
+
+    Ok(())
+}
+
+const STREAM_USAGE: &str =
+    "Usage: day1 stream [--input <file>|-|--input-name <name>] [--inputs-dir <dir>]
+                   [--run-len <values>] [--fan-in <runs>] [--temp-dir <dir>]
+
+Solves both parts while reading the input, for lists too large to be held in
+memory. Each list is sorted in runs of --run-len values, written to --temp-dir
+and merged --fan-in runs at a time.";
+
+struct StreamArgs {
+    source: InputSource,
+    options: stream::Options,
+}
+
+fn parse_stream_args(args: impl Iterator<Item = String>) -> Result<StreamArgs, String> {
+    let mut options = stream::Options::default();
+
+    let count = |value: Option<String>, flag: &str| -> Result<usize, String> {
+        let value = value.ok_or(format!("{flag} expects a number"))?;
+        value
+            .parse()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or(format!("Invalid {flag} '{value}'"))
+    };
+
+    let input = InputArgs::parse(args, |arg, args| {
+        match arg {
+            "--run-len" => options.run_len = count(args.next(), "--run-len")?,
+            "--fan-in" => options.fan_in = count(args.next(), "--fan-in")?.max(2),
+            "--temp-dir" => {
+                let value = args.next().ok_or("--temp-dir expects a directory")?;
+                options.temp_dir = value.into();
+            }
+            _ => return Err(format!("Unexpected argument '{arg}'")),
+        }
+
+        Ok(())
+    })?;
+
+    Ok(StreamArgs {
+        source: input.source(1),
+        options,
+    })
+}
+
+fn run_stream(args: StreamArgs) -> Result<(), String> {
+    let answers = match &args.source {
+        InputSource::File(path) => {
+            let file =
+                File::open(path).map_err(|e| format!("Cannot read '{}': {e}", path.display()))?;
+            stream::solve(BufReader::new(file), &args.options)
+        }
+        InputSource::Stdin => stream::solve(io::stdin().lock(), &args.options),
+    }
+    .map_err(|e| e.to_string())?;
+
+    cli::print_header(&super::Day1, None);
+    for (part, answer) in (1..).zip(&answers) {
+        cli::print_answer(part, answer, None, None);
+    }
+
+    Ok(())
+}
+
+/// Entry point of the `day1` binary, which can also compare any number of
+/// location lists with `day1 compare`, explain its answers with `day1 diff`,
+/// and solve inputs too large to be held in memory with `day1 stream`.
+pub fn main() -> ExitCode {
+    let mut args = cli::args().peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("compare") => {
+            args.next();
+            let r = parse_compare_args(args)
+                .map_err(|e| format!("{e}\n{COMPARE_USAGE}"))
+                .and_then(run_compare);
+
+            cli::exit(r)
+        }
+        Some("diff") => {
+            args.next();
+            let r = parse_diff_args(args)
+                .map_err(|e| format!("{e}\n{DIFF_USAGE}"))
+                .and_then(run_diff);
+
+            cli::exit(r)
+        }
+        Some("stream") => {
+            args.next();
+            let r = parse_stream_args(args)
+                .map_err(|e| format!("{e}\n{STREAM_USAGE}"))
+                .and_then(run_stream);
+
+            cli::exit(r)
+        }
+        _ => cli::run_day_binary(&super::Day1, args),
+    }
+}
@@ -0,0 +1,304 @@
+//! Comparison of location lists, two at a time, with a choice of metrics.
+//!
+//! Lists are the columns of the input. Part 1 is the sorted L1 distance
+//! between the first two, and part 2 their similarity score.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::json::Json;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Score {
+    Int(i64),
+    Float(f64),
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Score::Int(v) => v.fmt(f),
+            Score::Float(v) => write!(f, "{v:.3}"),
+        }
+    }
+}
+
+impl From<Score> for Answer {
+    fn from(score: Score) -> Self {
+        match score {
+            Score::Int(v) => v.into(),
+            Score::Float(_) => score.to_string().into(),
+        }
+    }
+}
+
+impl Score {
+    pub fn to_json(self) -> Json {
+        match self {
+            Score::Int(v) => Json::from(v),
+            Score::Float(v) => Json::from(v),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Metric {
+    /// Sum of the distances between the lists once sorted, as in part 1.
+    SortedL1,
+    /// Euclidean distance between the lists once sorted.
+    SortedL2,
+    /// Sum of the distances between the ranks of the values of each line,
+    /// Spearman's footrule. Tied values share their average rank.
+    Spearman,
+    /// Number of values found in both lists, counting repeated ones.
+    Intersection,
+    /// Values of the left list weighted by their count in the right one, as
+    /// in part 2.
+    Similarity,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 5] = [
+        Metric::SortedL1,
+        Metric::SortedL2,
+        Metric::Spearman,
+        Metric::Intersection,
+        Metric::Similarity,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::SortedL1 => "sorted-l1",
+            Metric::SortedL2 => "sorted-l2",
+            Metric::Spearman => "spearman",
+            Metric::Intersection => "intersection",
+            Metric::Similarity => "similarity",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.name() == s)
+    }
+
+    /// Compares two lists of the same length.
+    pub fn score(self, left: &[i64], right: &[i64]) -> Score {
+        match self {
+            Metric::SortedL1 => {
+                Score::Int(sorted_pairs(left, right).map(|(l, r)| (l - r).abs()).sum())
+            }
+            Metric::SortedL2 => Score::Float(
+                sorted_pairs(left, right)
+                    .map(|(l, r)| ((l - r) as f64).powi(2))
+                    .sum::<f64>()
+                    .sqrt(),
+            ),
+            Metric::Spearman => Score::Float(
+                ranks(left)
+                    .iter()
+                    .zip(ranks(right))
+                    .map(|(l, r)| (l - r).abs())
+                    .sum(),
+            ),
+            Metric::Intersection => {
+                let mut counts = counts(right);
+                let mut r = 0;
+
+                for v in left {
+                    if let Some(c @ 1..) = counts.get_mut(v) {
+                        *c -= 1;
+                        r += 1;
+                    }
+                }
+
+                Score::Int(r)
+            }
+            Metric::Similarity => {
+                let counts = counts(right);
+
+                Score::Int(left.iter().map(|v| v * counts.get(v).unwrap_or(&0)).sum())
+            }
+        }
+    }
+}
+
+fn sorted(values: &[i64]) -> Vec<i64> {
+    let mut values = values.to_vec();
+    values.sort();
+    values
+}
+
+pub(super) fn sorted_pairs(left: &[i64], right: &[i64]) -> impl Iterator<Item = (i64, i64)> {
+    sorted(left).into_iter().zip(sorted(right))
+}
+
+fn counts(values: &[i64]) -> HashMap<i64, i64> {
+    let mut counts = HashMap::new();
+
+    for &v in values {
+        *counts.entry(v).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// Rank of each value, starting at 1, tied values getting their average rank.
+fn ranks(values: &[i64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by_key(|&i| values[i]);
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut before = 0;
+
+    for tie in order.chunk_by(|&a, &b| values[a] == values[b]) {
+        let rank = before as f64 + (tie.len() as f64 + 1.0) / 2.0;
+
+        for &i in tie {
+            ranks[i] = rank;
+        }
+        before += tie.len();
+    }
+
+    ranks
+}
+
+/// Which columns are compared.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Pairing {
+    /// Every pair of columns.
+    All,
+    /// Each column against the given one, starting at 0.
+    Reference(usize),
+}
+
+impl Pairing {
+    /// Pairs of columns, the reference being on the left.
+    pub fn pairs(self, columns: usize) -> Vec<(usize, usize)> {
+        match self {
+            Pairing::All => (0..columns)
+                .flat_map(|l| (l + 1..columns).map(move |r| (l, r)))
+                .collect(),
+            Pairing::Reference(reference) => (0..columns)
+                .filter(|&c| c != reference)
+                .map(|c| (reference, c))
+                .collect(),
+        }
+    }
+}
+
+/// Scores of a pair of columns, numbered from 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PairReport {
+    pub left: usize,
+    pub right: usize,
+    pub scores: Vec<(Metric, Score)>,
+}
+
+pub fn compare(columns: &[Vec<i64>], pairing: Pairing, metrics: &[Metric]) -> Vec<PairReport> {
+    pairing
+        .pairs(columns.len())
+        .into_iter()
+        .map(|(left, right)| PairReport {
+            left,
+            right,
+            scores: metrics
+                .iter()
+                .map(|m| (*m, m.score(&columns[left], &columns[right])))
+                .collect(),
+        })
+        .collect()
+}
+
+/// One line per pair and one column per metric. Columns are numbered from 1.
+pub fn table(reports: &[PairReport], metrics: &[Metric]) -> String {
+    let mut header = format!("{:<7}", "Columns");
+    for m in metrics {
+        header += &format!("  {:>14}", m.name());
+    }
+
+    let mut lines = vec![header];
+
+    for r in reports {
+        let mut line = format!("{:<7}", format!("{}-{}", r.left + 1, r.right + 1));
+        for (_, score) in &r.scores {
+            line += &format!("  {:>14}", score.to_string());
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+pub fn to_json(reports: &[PairReport]) -> Json {
+    Json::Array(
+        reports
+            .iter()
+            .map(|r| {
+                let scores = r.scores.iter().map(|(m, s)| (m.name(), s.to_json()));
+
+                Json::object([
+                    ("left", Json::from(r.left + 1)),
+                    ("right", Json::from(r.right + 1)),
+                    ("scores", Json::object(scores)),
+                ])
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::parse_columns;
+    use crate::input::test_named_input;
+
+    #[test]
+    fn metrics() {
+        let columns = parse_columns(&test_named_input(1, "example")).unwrap();
+        let (left, right) = (&columns[0], &columns[1]);
+
+        assert_eq!(Metric::SortedL1.score(left, right), Score::Int(11));
+        assert_eq!(
+            Metric::SortedL2.score(left, right),
+            Score::Float(35f64.sqrt())
+        );
+        assert_eq!(Metric::Intersection.score(left, right), Score::Int(4));
+        assert_eq!(Metric::Similarity.score(left, right), Score::Int(31));
+        assert_eq!(Metric::Similarity.score(right, left), Score::Int(31));
+
+        assert_eq!(ranks(&[30, 10, 20, 10]), vec![4.0, 1.5, 3.0, 1.5]);
+        assert_eq!(
+            Metric::Spearman.score(&[1, 2, 3], &[10, 20, 30]),
+            Score::Float(0.0)
+        );
+        assert_eq!(
+            Metric::Spearman.score(&[1, 2, 3], &[30, 20, 10]),
+            Score::Float(4.0)
+        );
+
+        assert_eq!(Pairing::All.pairs(3), vec![(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(Pairing::Reference(1).pairs(3), vec![(1, 0), (1, 2)]);
+
+        let columns = parse_columns("1 2 3\n4   5\t6\n").unwrap();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        let metrics = [Metric::SortedL1, Metric::SortedL2];
+        let reports = compare(&columns, Pairing::Reference(0), &metrics);
+
+        assert_eq!(
+            table(&reports, &metrics),
+            "Columns       sorted-l1       sorted-l2
+1-2                   2           1.414
+1-3                   4           2.828"
+        );
+        assert_eq!(
+            to_json(&reports[..1]).to_string(),
+            r#"[{"left":1,"right":2,"scores":{"sorted-l1":2,"sorted-l2":1.4142135623730951}}]"#
+        );
+
+        let err = parse_columns("1 2\n3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 1: expected 2 columns, found 1: '3'"
+        );
+    }
+}
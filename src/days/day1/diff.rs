@@ -0,0 +1,274 @@
+//! Report of where the two lists disagree, explaining both answers: the
+//! sorted pairs behind the distance, and the counts behind the similarity.
+
+use std::collections::BTreeMap;
+
+use super::compare::sorted_pairs;
+use crate::json::Json;
+
+/// Values found at the same rank once both lists are sorted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pair {
+    /// Starting at 0.
+    pub rank: usize,
+    pub left: i64,
+    pub right: i64,
+    pub distance: i64,
+}
+
+/// Occurrences of a value in each list.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ValueCount {
+    pub value: i64,
+    pub left: i64,
+    pub right: i64,
+}
+
+impl ValueCount {
+    /// Contribution of the value to the similarity score.
+    pub fn score(&self) -> i64 {
+        self.value * self.left * self.right
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diff {
+    pub pairs: Vec<Pair>,
+    /// Sorted by value.
+    pub values: Vec<ValueCount>,
+}
+
+impl Diff {
+    pub fn new(left: &[i64], right: &[i64]) -> Self {
+        let pairs = sorted_pairs(left, right)
+            .enumerate()
+            .map(|(rank, (left, right))| Pair {
+                rank,
+                left,
+                right,
+                distance: (left - right).abs(),
+            })
+            .collect();
+
+        let mut values = BTreeMap::new();
+        for &v in left {
+            values.entry(v).or_insert((0, 0)).0 += 1;
+        }
+        for &v in right {
+            values.entry(v).or_insert((0, 0)).1 += 1;
+        }
+
+        let values = values
+            .into_iter()
+            .map(|(value, (left, right))| ValueCount { value, left, right })
+            .collect();
+
+        Self { pairs, values }
+    }
+
+    /// The answer of part 1.
+    pub fn distance(&self) -> i64 {
+        self.pairs.iter().map(|p| p.distance).sum()
+    }
+
+    /// The answer of part 2.
+    pub fn similarity(&self) -> i64 {
+        self.values.iter().map(ValueCount::score).sum()
+    }
+
+    /// The `k` pairs with the largest distances, the first ones in sorted
+    /// order on ties.
+    pub fn top(&self, k: usize) -> Vec<Pair> {
+        let mut pairs = self.pairs.clone();
+        pairs.sort_by_key(|p| std::cmp::Reverse(p.distance));
+        pairs.truncate(k);
+        pairs
+    }
+
+    /// Values of the left list missing from the right one.
+    pub fn left_only(&self) -> Vec<i64> {
+        self.values
+            .iter()
+            .filter(|c| c.right == 0)
+            .map(|c| c.value)
+            .collect()
+    }
+
+    /// Values of the right list missing from the left one.
+    pub fn right_only(&self) -> Vec<i64> {
+        self.values
+            .iter()
+            .filter(|c| c.left == 0)
+            .map(|c| c.value)
+            .collect()
+    }
+}
+
+fn pair_table(pairs: &[Pair]) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{:>6}  {:>10}  {:>10}  {:>10}",
+        "Rank", "Left", "Right", "Distance"
+    )];
+
+    for p in pairs {
+        lines.push(format!(
+            "{:>6}  {:>10}  {:>10}  {:>10}",
+            p.rank + 1,
+            p.left,
+            p.right,
+            p.distance
+        ));
+    }
+
+    lines
+}
+
+fn value_list(values: &[i64]) -> String {
+    match values {
+        [] => "none".to_string(),
+        _ => values
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+/// The report, with the `top` largest distances. Ranks are numbered from 1.
+pub fn text(diff: &Diff, top: usize) -> String {
+    let mut lines = vec![format!("Sorted pairs, distance {}:", diff.distance())];
+    lines.extend(pair_table(&diff.pairs));
+
+    let top = diff.top(top);
+    lines.push(String::new());
+    lines.push(format!("Largest {} distances:", top.len()));
+    lines.extend(pair_table(&top));
+
+    lines.push(String::new());
+    lines.push(format!(
+        "Only in the left list: {}",
+        value_list(&diff.left_only())
+    ));
+    lines.push(format!(
+        "Only in the right list: {}",
+        value_list(&diff.right_only())
+    ));
+
+    lines.push(String::new());
+    lines.push(format!("Value counts, similarity {}:", diff.similarity()));
+    lines.push(format!(
+        "{:>10}  {:>6}  {:>6}  {:>12}",
+        "Value", "Left", "Right", "Score"
+    ));
+    for c in &diff.values {
+        lines.push(format!(
+            "{:>10}  {:>6}  {:>6}  {:>12}",
+            c.value,
+            c.left,
+            c.right,
+            c.score()
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn pair_json(p: &Pair) -> Json {
+    Json::object([
+        ("rank", Json::from(p.rank + 1)),
+        ("left", Json::from(p.left)),
+        ("right", Json::from(p.right)),
+        ("distance", Json::from(p.distance)),
+    ])
+}
+
+fn values_json(values: Vec<i64>) -> Json {
+    Json::Array(values.into_iter().map(Json::from).collect())
+}
+
+pub fn to_json(diff: &Diff, top: usize) -> Json {
+    let counts = diff.values.iter().map(|c| {
+        Json::object([
+            ("value", Json::from(c.value)),
+            ("left", Json::from(c.left)),
+            ("right", Json::from(c.right)),
+            ("score", Json::from(c.score())),
+        ])
+    });
+
+    Json::object([
+        ("distance", Json::from(diff.distance())),
+        ("similarity", Json::from(diff.similarity())),
+        (
+            "pairs",
+            Json::Array(diff.pairs.iter().map(pair_json).collect()),
+        ),
+        (
+            "top",
+            Json::Array(diff.top(top).iter().map(pair_json).collect()),
+        ),
+        ("left_only", values_json(diff.left_only())),
+        ("right_only", values_json(diff.right_only())),
+        ("counts", Json::Array(counts.collect())),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::parse_input;
+    use crate::input::{test_input, test_named_input};
+
+    #[test]
+    fn diff() {
+        let (left, right) = parse_input(&test_named_input(1, "example")).unwrap();
+        let diff = Diff::new(&left, &right);
+
+        assert_eq!(diff.distance(), 11);
+        assert_eq!(diff.similarity(), 31);
+        assert_eq!(
+            diff.pairs[0],
+            Pair {
+                rank: 0,
+                left: 1,
+                right: 3,
+                distance: 2
+            }
+        );
+        assert_eq!(
+            diff.top(3)
+                .iter()
+                .map(|p| (p.rank, p.distance))
+                .collect::<Vec<_>>(),
+            vec![(5, 5), (0, 2), (4, 2)]
+        );
+        assert_eq!(diff.left_only(), vec![1, 2]);
+        assert_eq!(diff.right_only(), vec![5, 9]);
+        assert_eq!(
+            diff.values[2],
+            ValueCount {
+                value: 3,
+                left: 3,
+                right: 3
+            }
+        );
+
+        let text = text(&diff, 1);
+        assert!(text.starts_with("Sorted pairs, distance 11:\n  Rank"));
+        assert!(text.contains("Largest 1 distances:\n"));
+        assert!(text.contains("\nOnly in the left list: 1, 2\n"));
+        assert!(text.contains("\n         3       3       3            27"));
+
+        let json = to_json(&Diff::new(&[1], &[4]), 1).to_string();
+        assert_eq!(
+            json,
+            r#"{"distance":3,"similarity":0,"pairs":[{"rank":1,"left":1,"right":4,"distance":3}],"top":[{"rank":1,"left":1,"right":4,"distance":3}],"left_only":[1],"right_only":[4],"counts":[{"value":1,"left":1,"right":0,"score":0},{"value":4,"left":0,"right":1,"score":0}]}"#
+        );
+
+        let input = test_input(1);
+        let (left, right) = parse_input(&input).unwrap();
+        let diff = Diff::new(&left, &right);
+        assert_eq!(diff.distance(), 1258579);
+        assert_eq!(diff.similarity(), 23981443);
+    }
+}
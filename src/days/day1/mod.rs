@@ -0,0 +1,104 @@
+use crate::answer::Answer;
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
+
+pub mod cli;
+pub mod compare;
+pub mod diff;
+pub mod stream;
+
+use compare::Metric;
+
+const DAY: u32 = 1;
+
+/// Values of a line of whitespace-separated columns. There must be
+/// `columns` of them when that is known, from the previous lines.
+fn parse_row(l: &Line, columns: Option<usize>) -> Result<Vec<i64>, ParseError> {
+    let fields: Vec<&str> = l.text.split_whitespace().collect();
+
+    match columns {
+        None if fields.is_empty() => Err(l.error(l.text, "expected at least one column")),
+        Some(n) if fields.len() != n => Err(l.error(
+            l.text,
+            format!("expected {n} columns, found {}", fields.len()),
+        )),
+        _ => fields.into_iter().map(|f| l.parse(f)).collect(),
+    }
+}
+
+/// Lists given as whitespace-separated columns, all lines having the same
+/// number of columns.
+pub fn parse_columns(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut columns: Vec<Vec<i64>> = vec![];
+
+    for l in parse::lines(DAY, input) {
+        let row = parse_row(&l, (!columns.is_empty()).then_some(columns.len()))?;
+
+        columns.resize(row.len(), vec![]);
+        for (column, v) in columns.iter_mut().zip(row) {
+            column.push(v);
+        }
+    }
+
+    Ok(columns)
+}
+
+/// The left and right lists of the puzzle, which are the first two columns.
+pub fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let mut columns = parse_columns(input)?.into_iter();
+
+    match (columns.next(), columns.next()) {
+        (Some(left), Some(right)) => Ok((left, right)),
+        _ => Err(ParseError::end_of_input(DAY, input, "expected two columns")),
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let (left, right) = parse_input(input)?;
+
+    Ok(Metric::SortedL1.score(&left, &right).into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let (left, right) = parse_input(input)?;
+
+    Ok(Metric::Similarity.score(&left, &right).into())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+
+    #[test]
+    fn day1() {
+        let input = test_input(1);
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(1258579)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(23981443)));
+    }
+}
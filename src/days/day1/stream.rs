@@ -0,0 +1,325 @@
+//! Streaming solver, for lists too large to be held in memory.
+//!
+//! Lines are read one at a time. Each list is sorted by an external merge
+//! sort: sorted runs of at most `run_len` values are written to temporary
+//! files, then merged at most `fan_in` at a time. The right list is also
+//! counted in a map, whose size only depends on the number of distinct
+//! values, for the similarity score.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{parse_row, DAY};
+use crate::answer::Answer;
+use crate::parse::{Line, ParseError};
+
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Values of a list sorted in memory at once.
+    pub run_len: usize,
+    /// Runs merged at once.
+    pub fan_in: usize,
+    /// Directory of the runs.
+    pub temp_dir: PathBuf,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            run_len: 1 << 20,
+            fan_in: 64,
+            temp_dir: std::env::temp_dir(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "I/O error: {e}"),
+            StreamError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+type Values<'a> = Box<dyn Iterator<Item = io::Result<i64>> + 'a>;
+
+/// A sorted run of values in a temporary file, removed when dropped.
+struct Run {
+    path: PathBuf,
+}
+
+impl Run {
+    fn write(dir: &Path, values: impl Iterator<Item = io::Result<i64>>) -> io::Result<Self> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+        let n = NEXT.fetch_add(1, Ordering::Relaxed);
+        let run = Self {
+            path: dir.join(format!("aoc-day1-{}-{n}.run", std::process::id())),
+        };
+
+        let mut out = BufWriter::new(File::create(&run.path)?);
+        for v in values {
+            out.write_all(&v?.to_le_bytes())?;
+        }
+        out.flush()?;
+
+        Ok(run)
+    }
+
+    fn values(&self) -> io::Result<impl Iterator<Item = io::Result<i64>>> {
+        let mut reader = BufReader::new(File::open(&self.path)?);
+
+        Ok(std::iter::from_fn(move || {
+            let mut bytes = [0; 8];
+            match reader.read_exact(&mut bytes) {
+                Ok(()) => Some(Ok(i64::from_le_bytes(bytes))),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => None,
+                Err(e) => Some(Err(e)),
+            }
+        }))
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Merges sorted runs into sorted values.
+fn merge(runs: Vec<Run>) -> io::Result<Values<'static>> {
+    let mut sources = runs
+        .iter()
+        .map(|r| r.values())
+        .collect::<io::Result<Vec<_>>>()?;
+    let mut heap = BinaryHeap::new();
+
+    for (idx, source) in sources.iter_mut().enumerate() {
+        if let Some(v) = source.next() {
+            heap.push(Reverse((v?, idx)));
+        }
+    }
+
+    Ok(Box::new(std::iter::from_fn(move || {
+        // The files are open, but their runs must stay alive to be removed
+        // only once merged
+        let _ = &runs;
+
+        let Reverse((v, idx)) = heap.pop()?;
+        match sources[idx].next() {
+            Some(Ok(next)) => heap.push(Reverse((next, idx))),
+            Some(Err(e)) => return Some(Err(e)),
+            None => {}
+        }
+
+        Some(Ok(v))
+    })))
+}
+
+/// External sort of a list.
+struct Sorter<'a> {
+    options: &'a Options,
+    buffer: Vec<i64>,
+    runs: Vec<Run>,
+}
+
+impl<'a> Sorter<'a> {
+    fn new(options: &'a Options) -> Self {
+        Self {
+            options,
+            buffer: vec![],
+            runs: vec![],
+        }
+    }
+
+    fn push(&mut self, v: i64) -> io::Result<()> {
+        self.buffer.push(v);
+
+        if self.buffer.len() >= self.options.run_len.max(1) {
+            self.flush()?;
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+
+        let values = self.buffer.drain(..).map(Ok);
+        self.runs.push(Run::write(&self.options.temp_dir, values)?);
+
+        Ok(())
+    }
+
+    /// The values, sorted. They stay in memory if they fit in a single run.
+    fn finish(mut self) -> io::Result<Values<'static>> {
+        if self.runs.is_empty() {
+            self.buffer.sort_unstable();
+            return Ok(Box::new(self.buffer.into_iter().map(Ok)));
+        }
+
+        if !self.buffer.is_empty() {
+            self.flush()?;
+        }
+
+        let fan_in = self.options.fan_in.max(2);
+        let mut runs = self.runs;
+
+        while runs.len() > fan_in {
+            let mut merged = vec![];
+            let mut runs_left = runs.into_iter();
+
+            loop {
+                let group: Vec<Run> = runs_left.by_ref().take(fan_in).collect();
+                if group.is_empty() {
+                    break;
+                }
+
+                merged.push(Run::write(&self.options.temp_dir, merge(group)?)?);
+            }
+
+            runs = merged;
+        }
+
+        merge(runs)
+    }
+}
+
+/// Solves both parts in a single pass over `reader`, with the same answers
+/// as `solve_part1` and `solve_part2`.
+pub fn solve(reader: impl BufRead, options: &Options) -> Result<[Answer; 2], StreamError> {
+    let mut left = Sorter::new(options);
+    let mut right = Sorter::new(options);
+    let mut counts = HashMap::new();
+
+    let mut columns = None;
+    let mut blank = None;
+
+    for (idx, text) in reader.lines().enumerate() {
+        let text = text?;
+        let mut text = text.trim_end_matches('\r');
+        if idx == 0 {
+            text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        }
+
+        // Like normalized inputs, blank lines are only allowed at the end
+        if text.trim().is_empty() {
+            blank = blank.or(Some(idx + 1));
+            continue;
+        }
+
+        if let Some(number) = blank {
+            let l = Line {
+                day: DAY,
+                number,
+                text: "",
+            };
+            parse_row(&l, columns)?;
+        }
+
+        let l = Line {
+            day: DAY,
+            number: idx + 1,
+            text,
+        };
+        let row = parse_row(&l, columns)?;
+        let [l_value, r_value, ..] = row[..] else {
+            return Err(l.error(l.text, "expected two columns").into());
+        };
+        columns = Some(row.len());
+
+        left.push(l_value)?;
+        right.push(r_value)?;
+        *counts.entry(r_value).or_insert(0) += 1;
+    }
+
+    if columns.is_none() {
+        return Err(ParseError::end_of_input(DAY, "", "expected two columns").into());
+    }
+
+    let mut distance = 0;
+    let mut similarity = 0;
+
+    for (l, r) in left.finish()?.zip(right.finish()?) {
+        let (l, r) = (l?, r?);
+
+        distance += (l - r).abs();
+        similarity += l * counts.get(&l).unwrap_or(&0);
+    }
+
+    Ok([distance.into(), similarity.into()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::{solve_part1, solve_part2};
+    use crate::input::{test_input, test_named_input};
+
+    #[test]
+    fn stream() {
+        let dir = std::env::temp_dir().join(format!("aoc-day1-stream-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // Runs of 7 values merged 3 at a time take a few passes
+        let options = Options {
+            run_len: 7,
+            fan_in: 3,
+            temp_dir: dir.clone(),
+        };
+
+        for input in [test_named_input(1, "example"), test_input(1)] {
+            let expected = [solve_part1(&input), solve_part2(&input)].map(Result::unwrap);
+
+            assert_eq!(solve(input.as_bytes(), &options).unwrap(), expected);
+            assert_eq!(
+                solve(input.as_bytes(), &Options::default()).unwrap(),
+                expected
+            );
+        }
+
+        // Runs are removed once merged
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+
+        let crlf = "\u{feff}3   4\r\n4   3\r\n\r\n";
+        assert_eq!(
+            solve(crlf.as_bytes(), &options).unwrap(),
+            [Answer::Int(0), Answer::Int(7)]
+        );
+
+        let err = solve("1 2\n\n3 4\n".as_bytes(), &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 1: expected 2 columns, found 0"
+        );
+        assert!(solve("1\n".as_bytes(), &options).is_err());
+        assert!(solve("".as_bytes(), &options).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::visualize::{Frame, Frames, Visualize};
+use crate::Solution;
+use std::collections::HashSet;
+
+const DAY: u32 = 10;
+
+fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    // Impassable tiles are marked with '.', and get an unreachable altitude
+    Grid::parse(DAY, input, |_, c| match c {
+        '.' => Some(10),
+        _ => c.to_digit(10),
+    })
+}
+
+fn find_trailhead(
+    input: &Grid<u32>,
+    found_paths: &mut Vec<Vec<(i32, i32)>>,
+    current_path: Vec<(i32, i32)>,
+    x: i32,
+    y: i32,
+    altitude: u32,
+) {
+    for ((next_x, next_y), &v) in input.neighbours4(x, y) {
+        if v != altitude {
+            continue;
+        }
+
+        let mut next_path = current_path.clone();
+        next_path.push((next_x, next_y));
+
+        if v == 9 {
+            found_paths.push(next_path);
+        } else {
+            find_trailhead(input, found_paths, next_path, next_x, next_y, altitude + 1);
+        }
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let input = parse_input(input)?;
+    let mut r = 0;
+
+    for ((x, y), &v) in input.iter() {
+        if v != 0 {
+            continue;
+        }
+
+        let mut found_paths = vec![];
+        find_trailhead(&input, &mut found_paths, vec![(x, y)], x, y, 1);
+
+        let unique_nine: HashSet<_> = found_paths
+            .into_iter()
+            .map(|path| path[path.len() - 1])
+            .collect();
+        r += unique_nine.len();
+    }
+
+    Ok(r.into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let input = parse_input(input)?;
+    let mut r = 0;
+
+    for ((x, y), &v) in input.iter() {
+        if v != 0 {
+            continue;
+        }
+
+        let mut found = vec![];
+        find_trailhead(&input, &mut found, vec![(x, y)], x, y, 1);
+
+        r += found.len();
+    }
+
+    Ok(r.into())
+}
+
+/// The trails of each trailhead in turn, heights on a trail being shown as
+/// letters from `a` to `j`.
+fn trail_frames(input: &str) -> Result<Frames<'static>, ParseError> {
+    let input = parse_input(input)?;
+
+    let mut map = Grid::new(input.width(), input.height(), '.');
+    for ((x, y), &v) in input.iter() {
+        if let Some(c) = char::from_digit(v, 10) {
+            *map.get_mut(x, y).unwrap() = c;
+        }
+    }
+
+    let trailheads: Vec<_> = input
+        .iter()
+        .filter(|(_, &v)| v == 0)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    Ok(Box::new(trailheads.into_iter().map(move |(x, y)| {
+        let mut found_paths = vec![];
+        find_trailhead(&input, &mut found_paths, vec![(x, y)], x, y, 1);
+
+        let mut grid = map.clone();
+        for &(x, y) in found_paths.iter().flatten() {
+            let c = grid.get_mut(x, y).unwrap();
+            // Paths share their first tiles
+            if let Some(h) = c.to_digit(10) {
+                *c = char::from(b'a' + h as u8);
+            }
+        }
+
+        let score = found_paths
+            .iter()
+            .map(|path| path[path.len() - 1])
+            .collect::<HashSet<_>>()
+            .len();
+
+        Frame {
+            grid,
+            caption: format!(
+                "trailhead ({x}, {y}): score {score}, rating {}",
+                found_paths.len()
+            ),
+        }
+    })))
+}
+
+pub struct Day10;
+
+impl Visualize for Day10 {
+    fn frames<'a>(&self, input: &'a str) -> Result<Frames<'a>, ParseError> {
+        trail_frames(input)
+    }
+}
+
+impl Solution for Day10 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Hoof It"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{test_input, test_named_input};
+
+    #[test]
+    fn day10() {
+        let input = test_input(10);
+        let example = test_named_input(10, "example1");
+
+        let frames: Vec<_> = trail_frames(&example).unwrap().collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            frames[0].to_string(),
+            "ba..9..
+c...8..
+d...7..
+efgh654
+...i..3
+...j..2
+.....01
+trailhead (1, 0): score 1, rating 1"
+        );
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(548)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(1252)));
+    }
+}
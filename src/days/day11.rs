@@ -0,0 +1,94 @@
+use crate::answer::Answer;
+use crate::parse::{self, ParseError};
+use crate::Solution;
+use std::collections::HashMap;
+
+const DAY: u32 = 11;
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let l = parse::single_line(DAY, input)?;
+
+    l.text.split(' ').map(|s| l.parse(s)).collect()
+}
+
+fn solve(raw_input: &str, iterations: usize) -> Result<u64, ParseError> {
+    let mut input: HashMap<u64, u64> = parse_input(raw_input)?
+        .into_iter()
+        .map(|stone| (stone, 1))
+        .collect();
+
+    for _ in 0..iterations {
+        let mut next_input = HashMap::new();
+
+        let mut add_value = |k: u64, v: u64| {
+            let entry = next_input.entry(k).or_insert(0);
+            *entry += v;
+        };
+
+        for (k, v) in input.into_iter() {
+            if k == 0 {
+                add_value(1, v);
+            } else {
+                let digits = k.ilog10() + 1;
+                if digits % 2 == 0 {
+                    let p = 10u64.pow(digits / 2);
+
+                    add_value(k / p, v);
+                    add_value(k % p, v);
+                } else {
+                    add_value(k * 2024, v);
+                }
+            }
+        }
+
+        input = next_input;
+    }
+
+    Ok(input.values().copied().sum())
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    solve(input, 25).map(Answer::from)
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    solve(input, 75).map(Answer::from)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+
+    #[test]
+    fn day11() {
+        let input = test_input(11);
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(194782)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(233007586663131)));
+    }
+}
@@ -0,0 +1,313 @@
+use crate::answer::Answer;
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
+
+const DAY: u32 = 13;
+
+#[derive(Debug)]
+struct Button {
+    dx: i64,
+    dy: i64,
+}
+
+#[derive(Debug)]
+struct Machine<'a> {
+    btn_a: Button,
+    btn_b: Button,
+    prize_x: i64,
+    prize_y: i64,
+    /// Line of the prize, where overflows are reported.
+    line: Line<'a>,
+}
+
+fn parse_axis(l: &Line, s: &str, prefix: &str) -> Result<i64, ParseError> {
+    l.parse(l.strip_prefix(s, prefix)?)
+}
+
+fn parse_button(l: &Line, name: &str) -> Result<Button, ParseError> {
+    let input = l.strip_prefix(l.text, &format!("Button {name}: "))?;
+    let (x, y) = l.split_once(input, ", ")?;
+
+    Ok(Button {
+        dx: parse_axis(l, x, "X+")?,
+        dy: parse_axis(l, y, "Y+")?,
+    })
+}
+
+fn parse_prize(l: &Line) -> Result<(i64, i64), ParseError> {
+    let input = l.strip_prefix(l.text, "Prize: ")?;
+    let (x, y) = l.split_once(input, ", ")?;
+
+    Ok((parse_axis(l, x, "X=")?, parse_axis(l, y, "Y=")?))
+}
+
+fn parse_machine<'a>(lines: &[Line<'a>]) -> Result<Machine<'a>, ParseError> {
+    let [a, b, prize] = lines else {
+        let l = lines.get(3).unwrap_or(lines.last().unwrap());
+        return Err(match lines.len() {
+            0..3 => l.error(l.end(), "incomplete machine"),
+            _ => l.error(l.text, "expected an empty line"),
+        });
+    };
+
+    let btn_a = parse_button(a, "A")?;
+    let btn_b = parse_button(b, "B")?;
+    let (prize_x, prize_y) = parse_prize(prize)?;
+
+    Ok(Machine {
+        btn_a,
+        btn_b,
+        prize_x,
+        prize_y,
+        line: *prize,
+    })
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<Machine<'_>>, ParseError> {
+    // Machines are separated by an empty line
+    parse::paragraphs(DAY, input)
+        .iter()
+        .map(|p| parse_machine(p))
+        .collect()
+}
+
+/// Presses of A and B reaching the prize moved by `offset` on both axes, if
+/// it can be reached. Computed on 128 bits, where an overflow is reported.
+fn solve(machine: &Machine, offset: i128) -> Result<Option<(i128, i128)>, ParseError> {
+    let overflow = || {
+        machine
+            .line
+            .error(machine.line.text, "overflows a 128-bit integer")
+    };
+
+    let (ax, ay) = (machine.btn_a.dx as i128, machine.btn_a.dy as i128);
+    let (bx, by) = (machine.btn_b.dx as i128, machine.btn_b.dy as i128);
+    let px = (machine.prize_x as i128)
+        .checked_add(offset)
+        .ok_or_else(overflow)?;
+    let py = (machine.prize_y as i128)
+        .checked_add(offset)
+        .ok_or_else(overflow)?;
+
+    // Cramer's rule, each term being a difference of two products
+    let cross = |a: i128, b: i128, c: i128, d: i128| {
+        a.checked_mul(b)
+            .zip(c.checked_mul(d))
+            .and_then(|(ab, cd)| ab.checked_sub(cd))
+            .ok_or_else(overflow)
+    };
+
+    let det = cross(ax, by, ay, bx)?;
+    if det == 0 {
+        return Ok(None);
+    }
+
+    let a = cross(by, px, bx, py)?;
+    if a % det != 0 {
+        return Ok(None);
+    }
+
+    let b = cross(ax, py, ay, px)?;
+    if b % det != 0 {
+        return Ok(None);
+    }
+
+    // Buttons cannot be pressed a negative number of times
+    let (a, b) = (a / det, b / det);
+    Ok((a >= 0 && b >= 0).then_some((a, b)))
+}
+
+/// Tokens spent on the prizes that can be won, within `max_presses` of each
+/// button when given.
+fn tokens(input: &str, offset: i128, max_presses: Option<i128>) -> Result<Answer, ParseError> {
+    let machines = parse_inputs(input)?;
+    let mut r: i128 = 0;
+
+    for machine in machines {
+        let Some((a, b)) = solve(&machine, offset)? else {
+            continue;
+        };
+
+        if max_presses.is_some_and(|max| a > max || b > max) {
+            continue;
+        }
+
+        r = a
+            .checked_mul(3)
+            .and_then(|cost| cost.checked_add(b))
+            .and_then(|cost| r.checked_add(cost))
+            .ok_or_else(|| {
+                machine
+                    .line
+                    .error(machine.line.text, "overflows a 128-bit integer")
+            })?;
+    }
+
+    Ok(r.into())
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    tokens(input, 0, Some(100))
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    tokens(input, 10000000000000, None)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_inputs(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+    use crate::rng::Rng;
+
+    #[test]
+    fn day13() {
+        let input = test_input(13);
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(36250)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(83232379451012)));
+
+        // Answers that do not fit in 64 bits are kept, overflowing 128 bits is
+        // an error
+        let max = i64::MAX;
+        let input = machine((1, 0), (0, 1), (max, max));
+        assert_eq!(
+            solve_part2(&input),
+            Ok(Answer::BigInt(4 * (max as i128 + 10000000000000)))
+        );
+
+        let input = machine((1, 1), (max, i64::MIN), (max, max));
+        let err = solve_part2(&input).unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (3, "overflows a 128-bit integer")
+        );
+    }
+
+    /// Buttons that are not collinear, as in the puzzle inputs.
+    fn buttons(rng: &mut Rng) -> [(i64, i64); 2] {
+        loop {
+            let [a, b] = [(); 2].map(|_| (rng.range(1..100), rng.range(1..100)));
+            if a.0 * b.1 != a.1 * b.0 {
+                return [a, b];
+            }
+        }
+    }
+
+    fn machine(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> String {
+        format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        )
+    }
+
+    /// Random machines, half of them having a prize at about 100 presses of
+    /// each button. Some of those need a negative number of presses.
+    fn generate(rng: &mut Rng) -> String {
+        let machines: Vec<_> = (0..rng.range(1..6))
+            .map(|_| loop {
+                let [a, b] = buttons(rng);
+                let prize = match rng.chance(0.5) {
+                    true => {
+                        let (na, nb) = (rng.range(-20..120), rng.range(-20..120));
+                        (na * a.0 + nb * b.0, na * a.1 + nb * b.1)
+                    }
+                    false => (rng.range(0..20_000), rng.range(0..20_000)),
+                };
+
+                if prize.0 >= 0 && prize.1 >= 0 {
+                    break machine(a, b, prize);
+                }
+            })
+            .collect();
+
+        machines.join("\n")
+    }
+
+    /// Tries every number of presses up to 100 for both buttons.
+    fn reference_part1(input: &str) -> Answer {
+        let numbers = |l: &str| -> Vec<i64> {
+            l.split(|c: char| !c.is_ascii_digit())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse().unwrap())
+                .collect()
+        };
+
+        let mut r = 0;
+
+        for machine in input.split("\n\n") {
+            let [ax, ay, bx, by, px, py] = numbers(machine)[..] else {
+                panic!("invalid machine {machine}");
+            };
+
+            let costs = (0..=100)
+                .flat_map(|a| (0..=100).map(move |b| (a, b)))
+                .filter(|(a, b)| a * ax + b * bx == px && a * ay + b * by == py)
+                .map(|(a, b)| 3 * a + b);
+            r += costs.min().unwrap_or(0);
+        }
+
+        r.into()
+    }
+
+    #[test]
+    fn properties() {
+        let mut rng = Rng::new(13);
+
+        for _ in 0..200 {
+            let input = generate(&mut rng);
+            assert_eq!(solve_part1(&input), Ok(reference_part1(&input)), "{input}");
+        }
+
+        // Presses are too many to try them all in part 2, so the machines
+        // are built from them instead
+        for _ in 0..200 {
+            let mut input = vec![];
+            let mut cost = 0;
+
+            while input.len() < 3 {
+                let [a, b] = buttons(&mut rng);
+                let (na, nb) = (
+                    rng.range(1..1_000_000_000_000),
+                    rng.range(1..1_000_000_000_000),
+                );
+                let prize = (
+                    na * a.0 + nb * b.0 - 10_000_000_000_000,
+                    na * a.1 + nb * b.1 - 10_000_000_000_000,
+                );
+
+                if prize.0 >= 0 && prize.1 >= 0 {
+                    input.push(machine(a, b, prize));
+                    cost += 3 * na + nb;
+                }
+            }
+
+            let input = input.join("\n");
+            assert_eq!(solve_part2(&input), Ok(cost.into()), "{input}");
+        }
+    }
+}
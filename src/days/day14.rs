@@ -0,0 +1,370 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parse::{self, Line, ParseError};
+use crate::visualize::{Frame, Frames, Visualize};
+use crate::{Params, Solution};
+use std::collections::HashMap;
+
+const DAY: u32 = 14;
+
+pub const INPUT_WIDTH: i32 = 101;
+pub const INPUT_HEIGHT: i32 = 103;
+
+#[derive(Debug)]
+struct Robot {
+    x: i32,
+    y: i32,
+    vx: i32,
+    vy: i32,
+}
+
+fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    let split_entry = |l: &Line, s: &str, prefix: &str| -> Result<(i32, i32), ParseError> {
+        let (x, y) = l.split_once(l.strip_prefix(s, prefix)?, ",")?;
+
+        Ok((l.parse(x)?, l.parse(y)?))
+    };
+
+    parse::lines(DAY, input)
+        .map(|l| {
+            let (p, v) = l.split_once(l.text, " ")?;
+            let (x, y) = split_entry(&l, p, "p=")?;
+            let (vx, vy) = split_entry(&l, v, "v=")?;
+
+            Ok(Robot { x, y, vx, vy })
+        })
+        .collect()
+}
+
+fn modulo(v: i32, m: i32) -> i32 {
+    let v = v % m;
+    if v >= 0 {
+        v
+    } else {
+        v + m
+    }
+}
+
+pub fn solve_part1(input: &str, width: i32, height: i32) -> Result<Answer, ParseError> {
+    const ITERATIONS: i32 = 100;
+    let mut robots = parse(input)?;
+
+    // Move everything
+    for r in &mut robots {
+        r.x = modulo(r.x + r.vx * ITERATIONS, width);
+        r.y = modulo(r.y + r.vy * ITERATIONS, height);
+    }
+
+    // Count quadrants
+    let mid_x = width / 2;
+    let mid_y = height / 2;
+
+    let mut quadrants = [0; 4];
+
+    for r in robots {
+        if r.x < mid_x && r.y < mid_y {
+            quadrants[0] += 1;
+        } else if r.x < mid_x && r.y > mid_y {
+            quadrants[1] += 1;
+        } else if r.x > mid_x && r.y < mid_y {
+            quadrants[2] += 1;
+        } else if r.x > mid_x && r.y > mid_y {
+            quadrants[3] += 1;
+        }
+        // Ignore robots that are in the middle
+    }
+
+    Ok(quadrants.iter().product::<u64>().into())
+}
+
+pub fn solve_part2(input: &str, width: i32, height: i32) -> Result<Answer, ParseError> {
+    let mut robots = parse(input)?;
+    let mut i = 0;
+
+    loop {
+        for r in &mut robots {
+            r.x = modulo(r.x + r.vx, width);
+            r.y = modulo(r.y + r.vy, height);
+        }
+
+        let mut positions = HashMap::new();
+        for r in &robots {
+            let entry = positions.entry((r.x, r.y)).or_insert(0);
+            *entry += 1;
+        }
+
+        i += 1;
+
+        let c = positions.values().filter(|&&v| v > 1).count();
+        if c == 0 {
+            crate::info!("no robots overlap after {i} seconds");
+            crate::debug!(
+                "robots after {i} seconds:\n{}",
+                Frame {
+                    grid: render(&robots, width, height),
+                    caption: String::new(),
+                }
+            );
+
+            return Ok(i.into());
+        }
+    }
+}
+
+/// Robot counts per tile, `.` for an empty one.
+fn render(robots: &[Robot], width: i32, height: i32) -> Grid<char> {
+    let mut grid = Grid::new(width as usize, height as usize, '.');
+
+    for r in robots {
+        let Some(c) = grid.get_mut(r.x, r.y) else {
+            continue;
+        };
+
+        *c = match *c {
+            '.' => '1',
+            '9' => '9',
+            c => char::from(c as u8 + 1),
+        };
+    }
+
+    grid
+}
+
+/// Robots every second, forever.
+fn robot_frames(input: &str, width: i32, height: i32) -> Result<Frames<'static>, ParseError> {
+    let mut robots = parse(input)?;
+
+    Ok(Box::new((0..).map(move |t| {
+        let grid = render(&robots, width, height);
+
+        for r in &mut robots {
+            r.x = modulo(r.x + r.vx, width);
+            r.y = modulo(r.y + r.vy, height);
+        }
+
+        Frame {
+            grid,
+            caption: format!("{t} seconds elapsed"),
+        }
+    })))
+}
+
+/// Size of the room given by the `width` and `height` parameters of an
+/// input, the room of the puzzle by default.
+fn room(params: &Params) -> Result<(i32, i32), ParseError> {
+    let size = |key: &str, default: i32| match params.get(key) {
+        None => Ok(default),
+        Some(v) => v.parse().ok().filter(|&v: &i32| v > 0).ok_or_else(|| {
+            ParseError::end_of_input(
+                DAY,
+                "",
+                format!("expected a positive room {key}, found '{v}'"),
+            )
+        }),
+    };
+
+    Ok((size("width", INPUT_WIDTH)?, size("height", INPUT_HEIGHT)?))
+}
+
+pub struct Day14;
+
+impl Visualize for Day14 {
+    fn frames<'a>(&self, input: &'a str) -> Result<Frames<'a>, ParseError> {
+        // Positions repeat after that many seconds
+        let period = (INPUT_WIDTH * INPUT_HEIGHT) as usize;
+
+        Ok(Box::new(
+            robot_frames(input, INPUT_WIDTH, INPUT_HEIGHT)?.take(period),
+        ))
+    }
+}
+
+impl Solution for Day14 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Restroom Redoubt"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input, INPUT_WIDTH, INPUT_HEIGHT)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input, INPUT_WIDTH, INPUT_HEIGHT)
+    }
+
+    fn part_with(&self, part: u32, input: &str, params: &Params) -> Result<Answer, ParseError> {
+        let (width, height) = room(params)?;
+
+        match part {
+            1 => solve_part1(input, width, height),
+            _ => solve_part2(input, width, height),
+        }
+    }
+
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{test_input, test_named_input};
+    use crate::rng::Rng;
+
+    #[test]
+    fn day14() {
+        let input = test_input(14);
+        let example = test_named_input(14, "example");
+
+        const TEST_INPUT_WIDTH: i32 = 11;
+        const TEST_INPUT_HEIGHT: i32 = 7;
+
+        assert_eq!(
+            solve_part1(&example, TEST_INPUT_WIDTH, TEST_INPUT_HEIGHT),
+            Ok(Answer::Int(12))
+        );
+
+        let room = |width: &str, height: &str| {
+            Params::from([
+                ("width".to_string(), width.to_string()),
+                ("height".to_string(), height.to_string()),
+            ])
+        };
+        assert_eq!(
+            Day14.part_with(1, &example, &room("11", "7")),
+            Ok(Answer::Int(12))
+        );
+        assert!(Day14.part_with(1, &example, &room("0", "7")).is_err());
+        assert_eq!(
+            solve_part1(&input, INPUT_WIDTH, INPUT_HEIGHT),
+            Ok(Answer::Int(224438715))
+        );
+
+        let frame = robot_frames(&example, TEST_INPUT_WIDTH, TEST_INPUT_HEIGHT)
+            .unwrap()
+            .nth(100)
+            .unwrap();
+        assert_eq!(
+            frame.to_string(),
+            "......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1....
+100 seconds elapsed"
+        );
+
+        assert_eq!(
+            solve_part2(&input, INPUT_WIDTH, INPUT_HEIGHT),
+            Ok(Answer::Int(7603))
+        );
+    }
+
+    /// A room of odd size, as in the puzzle, and robots in it.
+    fn generate(rng: &mut Rng) -> (String, i32, i32) {
+        let (width, height) = (rng.range(1..8) * 2 + 1, rng.range(1..8) * 2 + 1);
+
+        let robots: String = (0..rng.range(1..20))
+            .map(|_| {
+                format!(
+                    "p={},{} v={},{}\n",
+                    rng.range(0..width),
+                    rng.range(0..height),
+                    rng.range(-width..width + 1),
+                    rng.range(-height..height + 1)
+                )
+            })
+            .collect();
+
+        (robots, width as i32, height as i32)
+    }
+
+    /// Positions and velocities of the robots.
+    fn robots(input: &str) -> Vec<[i32; 4]> {
+        input
+            .lines()
+            .map(|l| {
+                let numbers: Vec<i32> = l
+                    .split(|c: char| c != '-' && !c.is_ascii_digit())
+                    .filter(|s| !s.is_empty())
+                    .map(|s| s.parse().unwrap())
+                    .collect();
+                numbers.try_into().unwrap()
+            })
+            .collect()
+    }
+
+    fn tick(robots: &mut [[i32; 4]], width: i32, height: i32) {
+        for [x, y, vx, vy] in robots {
+            *x = (*x + *vx).rem_euclid(width);
+            *y = (*y + *vy).rem_euclid(height);
+        }
+    }
+
+    /// Moves the robots one second at a time.
+    fn reference_part1(input: &str, width: i32, height: i32) -> Answer {
+        let mut robots = robots(input);
+        for _ in 0..100 {
+            tick(&mut robots, width, height);
+        }
+
+        let mut quadrants = [0u64; 4];
+        for [x, y, _, _] in robots {
+            if x != width / 2 && y != height / 2 {
+                quadrants[(x > width / 2) as usize * 2 + (y > height / 2) as usize] += 1;
+            }
+        }
+
+        quadrants.iter().product::<u64>().into()
+    }
+
+    /// Compares every pair of robots every second, until they all are on
+    /// different tiles. Positions repeat after `width * height` seconds, so
+    /// that this gives up after that.
+    fn reference_part2(input: &str, width: i32, height: i32) -> Option<Answer> {
+        let mut robots = robots(input);
+
+        for t in 1..=width * height {
+            tick(&mut robots, width, height);
+
+            let overlap =
+                (0..robots.len()).any(|i| (0..i).any(|j| robots[i][..2] == robots[j][..2]));
+            if !overlap {
+                return Some(t.into());
+            }
+        }
+
+        None
+    }
+
+    #[test]
+    fn properties() {
+        let mut rng = Rng::new(14);
+
+        for _ in 0..300 {
+            let (input, width, height) = generate(&mut rng);
+
+            assert_eq!(
+                solve_part1(&input, width, height),
+                Ok(reference_part1(&input, width, height)),
+                "{input}"
+            );
+
+            // Part 2 assumes that the robots end up apart
+            if let Some(seconds) = reference_part2(&input, width, height) {
+                assert_eq!(solve_part2(&input, width, height), Ok(seconds), "{input}");
+            }
+        }
+    }
+}
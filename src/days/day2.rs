@@ -0,0 +1,467 @@
+use std::cmp::Ordering;
+
+use crate::answer::Answer;
+use crate::json::Json;
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub mod cli;
+
+const DAY: u32 = 2;
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse::lines(DAY, input)
+        .map(|l| l.text.split(' ').map(|s| l.parse(s)).collect())
+        .collect()
+}
+
+/// Tolerances a report must satisfy to be safe.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SafetyRules {
+    /// Smallest difference between adjacent levels, plateaus aside.
+    pub min_step: i32,
+    /// Largest difference between adjacent levels.
+    pub max_step: i32,
+    /// Number of times the levels may switch between increasing and
+    /// decreasing. Plateaus keep the current direction.
+    pub direction_changes: usize,
+    /// Whether adjacent levels may be equal.
+    pub allow_plateaus: bool,
+    /// Number of levels that may be removed to make a report safe.
+    pub removable: usize,
+}
+
+/// Index of the direction of the levels in `SafetyRules::dampen`.
+const UNKNOWN: usize = 0;
+const INCREASING: usize = 1;
+const DECREASING: usize = 2;
+
+impl SafetyRules {
+    /// Strictly monotonic with steps of 1 to 3.
+    pub const PART1: Self = Self {
+        min_step: 1,
+        max_step: 3,
+        direction_changes: 0,
+        allow_plateaus: false,
+        removable: 0,
+    };
+
+    /// Same as part 1, once a single level is removed.
+    pub const PART2: Self = Self {
+        removable: 1,
+        ..Self::PART1
+    };
+
+    fn step_valid(&self, from: i32, to: i32) -> bool {
+        match (to - from).abs() {
+            0 => self.allow_plateaus,
+            d => (self.min_step..=self.max_step).contains(&d),
+        }
+    }
+
+    /// Whether the report satisfies the rules once at most `removable` of
+    /// its levels are removed.
+    pub fn is_safe(&self, report: &[i32]) -> bool {
+        self.dampen(report).is_some()
+    }
+
+    /// Indices of the fewest levels to remove for the report to satisfy the
+    /// rules, in increasing order, or `None` when more than `removable` of
+    /// them would be needed. This takes O(n · (removable + 1) ·
+    /// (direction_changes + 1)) for a report of n levels.
+    pub fn dampen(&self, report: &[i32]) -> Option<Vec<usize>> {
+        let n = report.len();
+        if n == 0 {
+            return Some(vec![]);
+        }
+
+        // No more levels than the report has can be removed, nor directions
+        // changed, which also keeps the bounds below from overflowing
+        let removable = self.removable.min(n);
+        let max_changes = self.direction_changes.min(n);
+
+        // Best way to keep each level, by direction of the last step and
+        // number of direction changes so far.
+        let states: [Vec<Option<Kept>>; 3] = std::array::from_fn(|_| vec![None; max_changes + 1]);
+        let mut kept = vec![states; n];
+
+        for i in 0..n {
+            if i <= removable {
+                kept[i][UNKNOWN][0] = Some(Kept {
+                    removed: i,
+                    previous: None,
+                });
+            }
+
+            for j in i.saturating_sub(removable + 1)..i {
+                if !self.step_valid(report[j], report[i]) {
+                    continue;
+                }
+
+                let step = match report[i].cmp(&report[j]) {
+                    Ordering::Less => Some(DECREASING),
+                    Ordering::Equal => None,
+                    Ordering::Greater => Some(INCREASING),
+                };
+
+                for direction in [UNKNOWN, INCREASING, DECREASING] {
+                    for changes in 0..=max_changes {
+                        let Some(previous) = kept[j][direction][changes] else {
+                            continue;
+                        };
+
+                        let (next_direction, next_changes) = match step {
+                            None => (direction, changes),
+                            Some(s) if direction == UNKNOWN || direction == s => (s, changes),
+                            Some(s) => (s, changes + 1),
+                        };
+
+                        let removed = previous.removed + (i - j - 1);
+                        if next_changes > max_changes || removed > removable {
+                            continue;
+                        }
+
+                        let state = &mut kept[i][next_direction][next_changes];
+                        if state.is_none_or(|s| removed < s.removed) {
+                            *state = Some(Kept {
+                                removed,
+                                previous: Some((j, direction, changes)),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        // The last kept level, with the levels after it removed.
+        let (mut level, mut direction, mut changes, _) = (0..n)
+            .flat_map(|i| {
+                let kept = &kept[i];
+                (0..3).flat_map(move |d| {
+                    (0..=max_changes)
+                        .filter_map(move |c| kept[d][c].map(|s| (i, d, c, s.removed + (n - 1 - i))))
+                })
+            })
+            .filter(|&(_, _, _, removed)| removed <= removable)
+            .min_by_key(|&(_, _, _, removed)| removed)?;
+
+        let mut removed: Vec<usize> = (level + 1..n).rev().collect();
+        loop {
+            match kept[level][direction][changes].and_then(|s| s.previous) {
+                Some((previous, d, c)) => {
+                    removed.extend((previous + 1..level).rev());
+                    (level, direction, changes) = (previous, d, c);
+                }
+                None => {
+                    removed.extend((0..level).rev());
+                    break;
+                }
+            }
+        }
+
+        removed.reverse();
+        Some(removed)
+    }
+}
+
+/// A level kept by `SafetyRules::dampen`.
+#[derive(Clone, Copy)]
+struct Kept {
+    /// Number of levels removed up to this one.
+    removed: usize,
+    /// Level kept before this one, with the direction and number of direction
+    /// changes at that level.
+    previous: Option<(usize, usize, usize)>,
+}
+
+/// Safe reports, by index starting at 0, along with the levels removed to
+/// make them safe.
+pub fn safe_reports(reports: &[Vec<i32>], rules: &SafetyRules) -> Vec<(usize, Vec<usize>)> {
+    reports
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, report)| {
+            let removed = rules.dampen(report)?;
+            if !removed.is_empty() {
+                crate::info!("report {report:?} is safe without levels {removed:?}");
+            }
+
+            Some((idx, removed))
+        })
+        .collect()
+}
+
+/// Number of safe reports.
+pub fn count_safe(input: &str, rules: &SafetyRules) -> Result<usize, ParseError> {
+    Ok(safe_reports(&parse_input(input)?, rules).len())
+}
+
+fn levels(report: &[i32]) -> String {
+    report
+        .iter()
+        .map(i32::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The reports made safe by removing levels, and which ones. Reports and
+/// levels are numbered from 1.
+pub fn dampen_text(reports: &[Vec<i32>], safe: &[(usize, Vec<usize>)]) -> String {
+    let dampened: Vec<_> = safe
+        .iter()
+        .filter(|(_, removed)| !removed.is_empty())
+        .collect();
+
+    let mut lines = vec![format!(
+        "{} safe reports out of {}, {} of them once levels are removed",
+        safe.len(),
+        reports.len(),
+        dampened.len()
+    )];
+
+    for (idx, removed) in dampened {
+        let report = &reports[*idx];
+        let removed: Vec<String> = removed
+            .iter()
+            .map(|&level| format!("{} ({})", level + 1, report[level]))
+            .collect();
+
+        lines.push(format!(
+            "Report {}: {}  without level{} {}",
+            idx + 1,
+            levels(report),
+            if removed.len() > 1 { "s" } else { "" },
+            removed.join(", ")
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Every safe report, with the levels removed to make it safe. Reports and
+/// levels are numbered from 1.
+pub fn dampen_json(reports: &[Vec<i32>], safe: &[(usize, Vec<usize>)]) -> Json {
+    let safe = safe.iter().map(|(idx, removed)| {
+        Json::object([
+            ("report", Json::from(idx + 1)),
+            (
+                "levels",
+                Json::Array(reports[*idx].iter().map(|&v| Json::from(v)).collect()),
+            ),
+            (
+                "removed",
+                Json::Array(removed.iter().map(|&level| Json::from(level + 1)).collect()),
+            ),
+        ])
+    });
+
+    Json::object([
+        ("reports", Json::from(reports.len())),
+        ("safe", Json::Array(safe.collect())),
+    ])
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    Ok(count_safe(input, &SafetyRules::PART1)?.into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    Ok(count_safe(input, &SafetyRules::PART2)?.into())
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{test_input, test_named_input};
+    use crate::rng::Rng;
+
+    #[test]
+    fn day2() {
+        let input = test_input(2);
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(314)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(373)));
+    }
+
+    #[test]
+    fn safe_reports() {
+        let example = test_named_input(2, "example");
+
+        let reports = parse_input(&example).unwrap();
+        let safe = super::safe_reports(&reports, &SafetyRules::PART2);
+        assert_eq!(
+            safe,
+            vec![(0, vec![]), (3, vec![2]), (4, vec![3]), (5, vec![])]
+        );
+
+        assert_eq!(
+            dampen_text(&reports, &safe),
+            "4 safe reports out of 6, 2 of them once levels are removed
+Report 4: 1 3 2 4 5  without level 3 (2)
+Report 5: 8 6 4 4 1  without level 4 (4)"
+        );
+
+        let rules = SafetyRules {
+            removable: 2,
+            ..SafetyRules::PART1
+        };
+        let reports = vec![vec![9, 1, 2, 9, 3]];
+        let safe = super::safe_reports(&reports, &rules);
+        assert_eq!(
+            dampen_json(&reports, &safe).to_string(),
+            r#"{"reports":1,"safe":[{"report":1,"levels":[9,1,2,9,3],"removed":[1,4]}]}"#
+        );
+        assert!(dampen_text(&reports, &safe).ends_with("without levels 1 (9), 4 (9)"));
+    }
+
+    #[test]
+    fn rules() {
+        let sensor = SafetyRules {
+            min_step: 2,
+            max_step: 5,
+            direction_changes: 1,
+            allow_plateaus: true,
+            removable: 0,
+        };
+
+        assert!(sensor.is_safe(&[1, 3, 3, 8, 6, 4]));
+        assert!(!sensor.is_safe(&[1, 3, 3, 8, 6, 7]));
+        assert!(!sensor.is_safe(&[1, 2, 4]));
+        assert!(SafetyRules {
+            removable: 1,
+            ..sensor
+        }
+        .is_safe(&[1, 2, 4]));
+        assert!(SafetyRules {
+            removable: 2,
+            ..sensor
+        }
+        .is_safe(&[9, 1, 3, 3, 8, 6, 7]));
+    }
+
+    /// Checks the rules on the report itself, removing nothing.
+    fn reference_steps(rules: &SafetyRules, report: &[i32]) -> bool {
+        let mut direction = None;
+        let mut changes = 0;
+
+        for w in report.windows(2) {
+            if !rules.step_valid(w[0], w[1]) {
+                return false;
+            }
+
+            let step = w[1].cmp(&w[0]);
+            if step == Ordering::Equal {
+                continue;
+            }
+
+            if direction.is_some_and(|d| d != step) {
+                changes += 1;
+            }
+            direction = Some(step);
+        }
+
+        changes <= rules.direction_changes
+    }
+
+    fn without(report: &[i32], removed: &[usize]) -> Vec<i32> {
+        (0..report.len())
+            .filter(|i| !removed.contains(i))
+            .map(|i| report[i])
+            .collect()
+    }
+
+    /// Fewest levels to remove, trying every subset of them.
+    fn reference(rules: &SafetyRules, report: &[i32]) -> Option<usize> {
+        (0..1u32 << report.len())
+            .filter(|&removed| {
+                let removed: Vec<usize> = (0..report.len())
+                    .filter(|&i| removed & (1 << i) != 0)
+                    .collect();
+
+                removed.len() <= rules.removable
+                    && reference_steps(rules, &without(report, &removed))
+            })
+            .map(|removed| removed.count_ones() as usize)
+            .min()
+    }
+
+    #[test]
+    fn dampen() {
+        assert_eq!(SafetyRules::PART2.dampen(&[7, 6, 4, 2, 1]), Some(vec![]));
+        assert_eq!(SafetyRules::PART2.dampen(&[1, 2, 9, 3, 4]), Some(vec![2]));
+        assert_eq!(SafetyRules::PART2.dampen(&[9, 1, 2, 3]), Some(vec![0]));
+        assert_eq!(SafetyRules::PART2.dampen(&[1, 2, 7, 8, 9]), None);
+
+        let rules = SafetyRules {
+            removable: 2,
+            ..SafetyRules::PART1
+        };
+        assert_eq!(rules.dampen(&[9, 1, 2, 9, 3]), Some(vec![0, 3]));
+
+        let rules = SafetyRules {
+            removable: usize::MAX,
+            ..SafetyRules::PART1
+        };
+        assert_eq!(rules.dampen(&[1, 2, 3]), Some(vec![]));
+        assert_eq!(rules.dampen(&[1, 9, 2]), Some(vec![1]));
+    }
+
+    #[test]
+    fn properties() {
+        let mut rng = Rng::new(2);
+
+        for _ in 0..2000 {
+            let min_step = rng.range(0..3) as i32;
+            let rules = SafetyRules {
+                min_step,
+                max_step: min_step + rng.range(0..4) as i32,
+                direction_changes: rng.index(3),
+                allow_plateaus: rng.chance(0.5),
+                removable: rng.index(4),
+            };
+
+            let report: Vec<i32> = (0..rng.index(10))
+                .map(|_| rng.range(0..12) as i32)
+                .collect();
+
+            let removed = rules.dampen(&report);
+            assert_eq!(
+                removed.as_ref().map(Vec::len),
+                reference(&rules, &report),
+                "{rules:?} {report:?}"
+            );
+
+            if let Some(removed) = removed {
+                assert!(removed.windows(2).all(|w| w[0] < w[1]), "{removed:?}");
+                assert!(
+                    reference_steps(&rules, &without(&report, &removed)),
+                    "{rules:?} {report:?} {removed:?}"
+                );
+            }
+        }
+    }
+}
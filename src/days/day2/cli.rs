@@ -0,0 +1,81 @@
+//! Subcommands of the `day2` binary.
+
+use std::process::ExitCode;
+
+use super::SafetyRules;
+use crate::cli::{self, Format, InputArgs};
+
+const DAMPEN_USAGE: &str =
+    "Usage: day2 dampen [--input <file>|-|--input-name <name>] [--inputs-dir <dir>]
+                   [--removable <levels>] [--format text|json]
+
+Shows the levels to remove for each report to be safe, at most --removable of
+them (1 by default, as in part 2). Reports and levels are numbered from 1.";
+
+struct DampenArgs {
+    input: InputArgs,
+    removable: usize,
+    format: Format,
+}
+
+fn parse_dampen_args(args: impl Iterator<Item = String>) -> Result<DampenArgs, String> {
+    let mut removable = SafetyRules::PART2.removable;
+    let mut format = Format::Text;
+
+    let input = InputArgs::parse(args, |arg, args| {
+        match arg {
+            "--removable" => {
+                let value = args.next().ok_or("--removable expects a number")?;
+                removable = value
+                    .parse()
+                    .map_err(|_| format!("Invalid --removable '{value}'"))?;
+            }
+            "--format" => format = Format::from_arg(args.next())?,
+            _ => return Err(format!("Unexpected argument '{arg}'")),
+        }
+
+        Ok(())
+    })?;
+
+    Ok(DampenArgs {
+        input,
+        removable,
+        format,
+    })
+}
+
+fn run_dampen(args: DampenArgs) -> Result<(), String> {
+    let input = args.input.read(2)?;
+    let rules = SafetyRules {
+        removable: args.removable,
+        ..SafetyRules::PART1
+    };
+
+    let reports = super::parse_input(&input).map_err(|e| e.to_string())?;
+    let safe = super::safe_reports(&reports, &rules);
+
+    match args.format {
+        Format::Text => println!("{}", super::dampen_text(&reports, &safe)),
+        Format::Json => println!("{}", super::dampen_json(&reports, &safe)),
+    }
+
+    Ok(())
+}
+
+/// Entry point of the `day2` binary, which can also show the levels removed
+/// by the Problem Dampener with `day2 dampen`.
+pub fn main() -> ExitCode {
+    let mut args = cli::args().peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("dampen") => {
+            args.next();
+            let r = parse_dampen_args(args)
+                .map_err(|e| format!("{e}\n{DAMPEN_USAGE}"))
+                .and_then(run_dampen);
+
+            cli::exit(r)
+        }
+        _ => cli::run_day_binary(&super::Day2, args),
+    }
+}
@@ -0,0 +1,134 @@
+use crate::answer::Answer;
+use crate::parse::ParseError;
+use crate::Solution;
+
+const DAY: u32 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+/// A number of 1 to 3 digits, and what follows it.
+fn number(s: &str) -> Option<(u64, &str)> {
+    let len = s.bytes().take(3).take_while(u8::is_ascii_digit).count();
+    if len == 0 {
+        return None;
+    }
+
+    Some((s[..len].parse().ok()?, &s[len..]))
+}
+
+/// Operands of a `mul`, from what follows `mul(`. As the puzzle states, they
+/// are numbers of 1 to 3 digits, so that `mul(1234,5)` and `mul(+2,3)` are
+/// corrupted.
+fn mul_operands(s: &str) -> Option<(u64, u64)> {
+    let (left, s) = number(s)?;
+    let (right, s) = number(s.strip_prefix(',')?)?;
+
+    s.starts_with(')').then_some((left, right))
+}
+
+/// Scans the corrupted memory for the valid instructions, which may start at
+/// any character.
+fn parse_input(input: &str) -> Vec<Instruction> {
+    let mut instructions = vec![];
+
+    for (idx, _) in input.char_indices() {
+        let s = &input[idx..];
+
+        if let Some(s) = s.strip_prefix("mul(") {
+            if let Some((left, right)) = mul_operands(s) {
+                instructions.push(Instruction::Mul(left, right));
+            }
+        } else if s.starts_with("do()") {
+            instructions.push(Instruction::Do);
+        } else if s.starts_with("don't()") {
+            instructions.push(Instruction::Dont);
+        }
+    }
+
+    instructions
+}
+
+pub fn solve_part1(input: &str) -> Answer {
+    let mut r = 0;
+
+    for instruction in parse_input(input) {
+        if let Instruction::Mul(left, right) = instruction {
+            r += left * right;
+        }
+    }
+
+    r.into()
+}
+
+pub fn solve_part2(input: &str) -> Answer {
+    let mut r = 0;
+    let mut enabled = true;
+
+    for instruction in parse_input(input) {
+        match instruction {
+            Instruction::Mul(left, right) if enabled => r += left * right,
+            Instruction::Mul(..) => {}
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+
+    r.into()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        // Corrupted memory is the puzzle itself, there is nothing to reject
+        parse_input(input);
+
+        Ok(())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part1(input))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        Ok(solve_part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+
+    #[test]
+    fn day3() {
+        let input = test_input(3);
+
+        assert_eq!(solve_part1(&input), Answer::Int(189600467));
+        assert_eq!(solve_part2(&input), Answer::Int(107069718));
+
+        assert_eq!(
+            parse_input("mul(1234,5)mul(12,345)mul(1,2]émul(3,4)don't()mul(,1)do("),
+            vec![
+                Instruction::Mul(12, 345),
+                Instruction::Mul(3, 4),
+                Instruction::Dont
+            ]
+        );
+        assert_eq!(solve_part1("mul(999,999"), Answer::Int(0));
+        assert_eq!(solve_part1("mul(+2,3)mul(2,+3)mul(2,3)"), Answer::Int(6));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::answer::Answer;
+use crate::geometry::Direction8;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::Solution;
+
+const DAY: u32 = 4;
+
+/// Reads at most `len` characters from a sequence of cells.
+fn read<'a>(cells: impl Iterator<Item = &'a char>, len: usize) -> Vec<char> {
+    cells.take(len).copied().collect()
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    const XMAS: &[char] = &['X', 'M', 'A', 'S'];
+    const XMAS_LEN: usize = XMAS.len();
+
+    let grid = Grid::parse_chars(DAY, input)?;
+
+    let mut c = 0;
+
+    for ((start_x, start_y), _) in grid.iter() {
+        for dir in Direction8::ALL {
+            let v = dir.vector();
+            let s = read(grid.ray(start_x, start_y, v.x, v.y), XMAS_LEN);
+            if s == XMAS {
+                c += 1;
+            }
+        }
+    }
+
+    Ok(c.into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    const MAS: &[char] = &['M', 'A', 'S'];
+    const SAM: &[char] = &['S', 'A', 'M'];
+    const MAS_LEN: usize = MAS.len();
+
+    let grid = Grid::parse_chars(DAY, input)?;
+
+    let mut c = 0;
+
+    for ((start_x, start_y), _) in grid.iter() {
+        let s = read(grid.diagonal(start_x, start_y), MAS_LEN);
+        if s != MAS && s != SAM {
+            continue;
+        }
+
+        let s = read(grid.anti_diagonal(start_x, start_y + 2), MAS_LEN);
+        if s != MAS && s != SAM {
+            continue;
+        }
+
+        c += 1;
+    }
+
+    Ok(c.into())
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        Grid::parse_chars(DAY, input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+
+    #[test]
+    fn day4() {
+        let input = test_input(4);
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(2562)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(1902)));
+    }
+}
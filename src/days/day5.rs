@@ -0,0 +1,180 @@
+use crate::answer::Answer;
+use crate::parse::{self, ParseError};
+use crate::Solution;
+use std::collections::HashMap;
+
+const DAY: u32 = 5;
+
+#[derive(Debug)]
+struct Input {
+    rules: HashMap<i32, Vec<i32>>,
+    updates: Vec<Vec<i32>>,
+}
+
+impl Input {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        // Rules come first, then the updates
+        let mut paragraphs = parse::paragraphs(DAY, input).into_iter();
+        let rule_lines = paragraphs.next().unwrap_or_default();
+        let update_lines = paragraphs.next().unwrap_or_default();
+
+        if let Some(p) = paragraphs.next() {
+            return Err(p[0].error(p[0].text, "expected rules and updates only"));
+        }
+
+        let mut rules: HashMap<i32, Vec<i32>> = HashMap::new();
+        for l in rule_lines {
+            let (before, after) = l.split_once(l.text, "|")?;
+
+            let entry = rules.entry(l.parse(before)?).or_default();
+            entry.push(l.parse(after)?);
+        }
+
+        let updates = update_lines
+            .iter()
+            .map(|l| l.text.split(',').map(|s| l.parse(s)).collect())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { rules, updates })
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let input = Input::new(input)?;
+    let mut r: i64 = 0;
+
+    for update in &input.updates {
+        let mut valid = true;
+        for i in 0..update.len() {
+            // Check if there is a rule for the current item
+            let Some(rule_entry) = input.rules.get(&update[i]) else {
+                continue;
+            };
+
+            // Get the items before the current one
+            let slice = &update[0..i];
+            if slice.is_empty() {
+                continue;
+            }
+
+            // Check for misplaced items
+            if rule_entry.iter().any(|item| slice.contains(item)) {
+                valid = false;
+                break;
+            }
+        }
+
+        if valid {
+            r += i64::from(update[update.len() / 2]);
+        }
+    }
+
+    Ok(r.into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let input = Input::new(input)?;
+    let mut r: i64 = 0;
+
+    for mut update in input.updates {
+        let mut valid = true;
+
+        loop {
+            // The current update has to be processed as long as there are
+            // misplaced items
+            let mut swapped = false;
+
+            for i in 1..update.len() {
+                let current: i32 = update[i];
+                // Check if there is a rule for the current item
+                let Some(current_rule) = input.rules.get(&current) else {
+                    continue;
+                };
+
+                // Get the items before the current one
+                let mut slice = vec![0; i];
+                slice.copy_from_slice(&update[0..i]);
+                if slice.is_empty() {
+                    continue;
+                }
+
+                // Look for any misplaced item in the preceding items.
+                // A misplaced pair is swapped to have a valid order.
+                for rule_item in current_rule {
+                    if let Some(idx) = slice.iter().enumerate().find_map(|(idx, &slice_item)| {
+                        if *rule_item == slice_item {
+                            Some(idx)
+                        } else {
+                            None
+                        }
+                    }) {
+                        let a = update[i];
+                        let b = update[idx];
+
+                        update[idx] = a;
+                        update[i] = b;
+                        crate::debug!("swap {b} and {a}, {a} must come before {b}");
+
+                        valid = false;
+                        swapped = true;
+
+                        break;
+                    }
+                }
+            }
+
+            if !swapped {
+                break;
+            }
+        }
+
+        if valid {
+            continue;
+        }
+
+        // At this point, valid updates have been ignored, and the remaining
+        // invalid updates have been fixed
+        crate::info!("fixed update {update:?}");
+        r += i64::from(update[update.len() / 2]);
+    }
+
+    Ok(r.into())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        Input::new(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+
+    #[test]
+    fn day5() {
+        let input = test_input(5);
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(7074)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(4828)));
+    }
+}
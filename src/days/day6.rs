@@ -0,0 +1,315 @@
+use crate::answer::Answer;
+use crate::geometry::{Direction4, Point};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::visualize::{Frame, Frames, Visualize};
+use crate::Solution;
+use std::collections::HashSet;
+
+const DAY: u32 = 6;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Item {
+    Empty,
+    Obstacle,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Guard {
+    pos: Point,
+    dir: Direction4,
+}
+
+fn parse_input(input: &str) -> Result<(Grid<Item>, Guard), ParseError> {
+    let mut guard = None;
+
+    let grid = Grid::parse(DAY, input, |(x, y), c| {
+        let dir = match c {
+            '.' => return Some(Item::Empty),
+            '#' => return Some(Item::Obstacle),
+            '^' => Direction4::Up,
+            'v' => Direction4::Down,
+            '<' => Direction4::Left,
+            '>' => Direction4::Right,
+            _ => return None,
+        };
+
+        guard = Some(Guard {
+            pos: Point::new(x, y),
+            dir,
+        });
+
+        Some(Item::Empty)
+    })?;
+
+    let guard = guard.ok_or_else(|| ParseError::end_of_input(DAY, input, "no guard in the map"))?;
+
+    Ok((grid, guard))
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let (grid, mut guard) = parse_input(input)?;
+    let mut positions = HashSet::from([guard.pos]);
+
+    loop {
+        let next_pos = guard.pos.step(guard.dir);
+
+        let Some(next) = grid.at(next_pos) else {
+            break;
+        };
+
+        if *next == Item::Obstacle {
+            guard.dir = guard.dir.turn_right();
+            crate::debug!(
+                "turn at ({}, {}), now facing {:?}",
+                guard.pos.x,
+                guard.pos.y,
+                guard.dir
+            );
+        } else {
+            guard.pos = next_pos;
+
+            positions.insert(guard.pos);
+        }
+    }
+
+    Ok(positions.len().into())
+}
+
+fn has_loop(grid: Grid<Item>, mut guard: Guard) -> bool {
+    let mut positions = HashSet::new();
+
+    loop {
+        let next_pos = guard.pos.step(guard.dir);
+
+        let Some(next) = grid.at(next_pos) else {
+            return false;
+        };
+
+        if *next == Item::Obstacle {
+            guard.dir = guard.dir.turn_right();
+        } else {
+            guard.pos = next_pos;
+        }
+
+        // Consider we are in a loop if the guard pass twice in the same location,
+        // with the same direction
+        if !positions.insert(guard.clone()) {
+            return true;
+        }
+    }
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let (grid, guard) = parse_input(input)?;
+
+    let mut r: usize = 0;
+
+    for ((x, y), item) in grid.iter() {
+        // Avoid to crush the guard with an obstacle
+        if Point::new(x, y) == guard.pos {
+            continue;
+        }
+
+        if *item == Item::Empty {
+            let mut grid = grid.clone();
+            *grid.get_mut(x, y).unwrap() = Item::Obstacle;
+
+            if has_loop(grid, guard.clone()) {
+                crate::debug!("obstacle at ({x}, {y}) traps the guard in a loop");
+                r += 1;
+            }
+        }
+    }
+
+    Ok(r.into())
+}
+
+fn guard_char(dir: Direction4) -> char {
+    match dir {
+        Direction4::Up => '^',
+        Direction4::Right => '>',
+        Direction4::Down => 'v',
+        Direction4::Left => '<',
+    }
+}
+
+/// The patrol of part 1, one frame per move or turn.
+fn patrol_frames(input: &str) -> Result<Frames<'static>, ParseError> {
+    let (grid, mut guard) = parse_input(input)?;
+
+    let mut canvas = Grid::new(grid.width(), grid.height(), '.');
+    for ((x, y), item) in grid.iter() {
+        if *item == Item::Obstacle {
+            *canvas.get_mut(x, y).unwrap() = '#';
+        }
+    }
+
+    let mut visited = HashSet::from([guard.pos]);
+    let mut done = false;
+
+    Ok(Box::new(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        let mut frame = canvas.clone();
+        *frame.at_mut(guard.pos).unwrap() = guard_char(guard.dir);
+        let caption = format!("{} positions visited", visited.len());
+
+        let next_pos = guard.pos.step(guard.dir);
+        match grid.at(next_pos) {
+            None => done = true,
+            Some(Item::Obstacle) => guard.dir = guard.dir.turn_right(),
+            Some(Item::Empty) => {
+                *canvas.at_mut(guard.pos).unwrap() = 'X';
+                guard.pos = next_pos;
+                visited.insert(guard.pos);
+            }
+        }
+
+        Some(Frame {
+            grid: frame,
+            caption,
+        })
+    })))
+}
+
+pub struct Day6;
+
+impl Visualize for Day6 {
+    fn frames<'a>(&self, input: &'a str) -> Result<Frames<'a>, ParseError> {
+        patrol_frames(input)
+    }
+}
+
+impl Solution for Day6 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{test_input, test_named_input};
+    use crate::rng::Rng;
+
+    #[test]
+    fn day6() {
+        let input = test_input(6);
+        let example = test_named_input(6, "example");
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(4967)));
+
+        let last = patrol_frames(&example).unwrap().last().unwrap();
+        assert_eq!(last.caption, "41 positions visited");
+        assert_eq!(last.grid.get(7, 9), Some(&'v'));
+        assert_eq!(last.grid.get(7, 8), Some(&'X'));
+
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(1789)));
+    }
+
+    /// A random map with a guard facing any direction.
+    fn generate(rng: &mut Rng) -> String {
+        let (width, height) = (rng.range(1..13) as usize, rng.range(1..13) as usize);
+        let density = rng.range(0..30) as f64 / 100.0;
+
+        let mut map: Vec<Vec<char>> = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.chance(density) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        map[rng.index(height)][rng.index(width)] = *rng.choose(&['^', '>', 'v', '<']);
+
+        map.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+
+    /// Walks the guard one step at a time, and returns the positions visited
+    /// or `None` if the guard never leaves.
+    fn walk(map: &[Vec<char>], obstacle: Option<(usize, usize)>) -> Option<HashSet<(i64, i64)>> {
+        const MOVES: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+        let (height, width) = (map.len() as i64, map[0].len() as i64);
+        let tile = |x: i64, y: i64| {
+            if x < 0 || y < 0 || x >= width || y >= height {
+                None
+            } else if obstacle == Some((x as usize, y as usize)) {
+                Some('#')
+            } else {
+                Some(map[y as usize][x as usize])
+            }
+        };
+
+        let (mut x, mut y, mut dir) = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .find_map(|(x, y)| {
+                let dir = "^>v<".find(tile(x, y)?)?;
+                Some((x, y, dir))
+            })?;
+
+        let mut visited = HashSet::from([(x, y)]);
+
+        // Past that many steps, some position has been left twice in the
+        // same direction
+        for _ in 0..=4 * width * height {
+            let (dx, dy) = MOVES[dir];
+            match tile(x + dx, y + dy) {
+                None => return Some(visited),
+                Some('#') => dir = (dir + 1) % 4,
+                Some(_) => {
+                    (x, y) = (x + dx, y + dy);
+                    visited.insert((x, y));
+                }
+            }
+        }
+
+        None
+    }
+
+    #[test]
+    fn properties() {
+        let mut rng = Rng::new(6);
+
+        for _ in 0..300 {
+            let input = generate(&mut rng);
+            let map: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+
+            // Part 1 assumes that the guard leaves
+            if let Some(visited) = walk(&map, None) {
+                assert_eq!(solve_part1(&input), Ok(visited.len().into()), "{input}");
+            }
+
+            let loops = (0..map.len())
+                .flat_map(|y| (0..map[0].len()).map(move |x| (x, y)))
+                .filter(|&(x, y)| map[y][x] == '.' && walk(&map, Some((x, y))).is_none())
+                .count();
+            assert_eq!(solve_part2(&input), Ok(loops.into()), "{input}");
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::answer::Answer;
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
+
+const DAY: u32 = 7;
+
+struct Equation<'a> {
+    line: Line<'a>,
+    target: u64,
+    values: Vec<u64>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Equation<'_>>, ParseError> {
+    parse::lines(DAY, input)
+        .map(|l| {
+            let (target, values) = l.split_once(l.text, ": ")?;
+            let values = values
+                .split(' ')
+                .map(|s| l.parse(s))
+                .collect::<Result<_, _>>()?;
+
+            Ok(Equation {
+                line: l,
+                target: l.parse(target)?,
+                values,
+            })
+        })
+        .collect()
+}
+
+/// `left` followed by the digits of `right`.
+fn concat(left: u64, right: u64) -> Option<u64> {
+    let digits = right.checked_ilog10().unwrap_or(0) + 1;
+
+    left.checked_mul(10u64.checked_pow(digits)?)?
+        .checked_add(right)
+}
+
+/// Every result of the remaining operations, or `None` if one of them
+/// overflows.
+fn compute_part1(results: &mut Vec<u64>, current: u64, remainings: &[u64]) -> Option<()> {
+    let Some((&value, remainings)) = remainings.split_first() else {
+        results.push(current);
+        return Some(());
+    };
+
+    compute_part1(results, current.checked_add(value)?, remainings)?;
+    compute_part1(results, current.checked_mul(value)?, remainings)
+}
+
+/// Sum of the targets of the equations that `compute` can solve.
+fn solve(
+    input: &str,
+    compute: fn(&mut Vec<u64>, u64, &[u64]) -> Option<()>,
+) -> Result<Answer, ParseError> {
+    let mut r: u64 = 0;
+
+    for Equation {
+        line,
+        target,
+        values,
+    } in parse_input(input)?
+    {
+        let overflow = || line.error(line.text, "overflows a 64-bit integer");
+
+        let mut results = vec![];
+        compute(&mut results, values[0], &values[1..]).ok_or_else(overflow)?;
+
+        if results.contains(&target) {
+            r = r.checked_add(target).ok_or_else(overflow)?;
+        }
+    }
+
+    Ok(r.into())
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    solve(input, compute_part1)
+}
+
+fn compute_part2(results: &mut Vec<u64>, current: u64, remainings: &[u64]) -> Option<()> {
+    let Some((&value, remainings)) = remainings.split_first() else {
+        results.push(current);
+        return Some(());
+    };
+
+    compute_part2(results, current.checked_add(value)?, remainings)?;
+    compute_part2(results, current.checked_mul(value)?, remainings)?;
+    compute_part2(results, concat(current, value)?, remainings)
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    solve(input, compute_part2)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Bridge Repair"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+    use crate::rng::Rng;
+
+    #[test]
+    fn day7() {
+        let input = test_input(7);
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(303876485655)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(146111650210682)));
+
+        assert_eq!(solve_part1("5: 5"), Ok(Answer::Int(5)));
+        assert_eq!(solve_part2("3: 1 0\n10: 1 0"), Ok(Answer::Int(10)));
+
+        let err = solve_part1("1: 2\n3: 18446744073709551615 2").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "overflows a 64-bit integer")
+        );
+        assert!(solve_part2("1: 99999999999 999999999").is_err());
+    }
+
+    /// Random equations, half of them built to be solvable with `ops`
+    /// operators.
+    fn generate(rng: &mut Rng, ops: u32) -> String {
+        let mut s = String::new();
+
+        for _ in 0..rng.range(1..10) {
+            let values: Vec<i64> = (0..rng.range(2..7))
+                .map(|_| match rng.chance(0.2) {
+                    true => rng.range(100..1000),
+                    false => rng.range(1..100),
+                })
+                .collect();
+
+            let target = match rng.chance(0.5) {
+                true => evaluate(&values, rng.next_u64(), ops),
+                false => rng.range(1..100_000),
+            };
+
+            let values: Vec<_> = values.iter().map(i64::to_string).collect();
+            s += &format!("{target}: {}\n", values.join(" "));
+        }
+
+        s
+    }
+
+    /// Evaluates the values left to right, with the operators given by the
+    /// digits of `combination` in base `ops`: add, multiply, concatenate.
+    fn evaluate(values: &[i64], mut combination: u64, ops: u32) -> i64 {
+        let mut r = values[0];
+
+        for v in &values[1..] {
+            r = match combination % ops as u64 {
+                0 => r + v,
+                1 => r * v,
+                _ => format!("{r}{v}").parse().unwrap(),
+            };
+            combination /= ops as u64;
+        }
+
+        r
+    }
+
+    /// Tries every combination of operators.
+    fn reference(input: &str, ops: u32) -> Answer {
+        let mut r = 0;
+
+        for l in input.lines() {
+            let (target, values) = l.split_once(": ").unwrap();
+            let target: i64 = target.parse().unwrap();
+            let values: Vec<i64> = values.split(' ').map(|v| v.parse().unwrap()).collect();
+
+            let combinations = (ops as u64).pow(values.len() as u32 - 1);
+            if (0..combinations).any(|c| evaluate(&values, c, ops) == target) {
+                r += target;
+            }
+        }
+
+        r.into()
+    }
+
+    #[test]
+    fn properties() {
+        let mut rng = Rng::new(7);
+
+        for _ in 0..200 {
+            let input = generate(&mut rng, 2);
+            assert_eq!(solve_part1(&input), Ok(reference(&input, 2)), "{input}");
+
+            let input = generate(&mut rng, 3);
+            assert_eq!(solve_part2(&input), Ok(reference(&input, 3)), "{input}");
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::answer::Answer;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::Solution;
+use std::collections::{HashMap, HashSet};
+
+const DAY: u32 = 8;
+
+struct Input {
+    antennas: HashMap<char, Vec<Point>>,
+    map: Grid<char>,
+}
+
+impl Input {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(DAY, input, |_, c| {
+            (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+        })?;
+
+        let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+
+        for (pos, &c) in map.iter() {
+            if c == '.' {
+                continue;
+            }
+
+            let entry = antennas.entry(c).or_default();
+            entry.push(pos.into());
+        }
+
+        Ok(Self { antennas, map })
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let input = Input::new(input)?;
+    let mut antinodes = HashSet::new();
+
+    for positions in input.antennas.values() {
+        for i in 0..positions.len() {
+            let pos = positions[i];
+
+            for (j, &other) in positions.iter().enumerate() {
+                if j == i {
+                    continue;
+                }
+
+                let antinode = other + (other - pos);
+
+                if input.map.at(antinode).is_none() {
+                    continue;
+                }
+
+                antinodes.insert(antinode);
+            }
+        }
+    }
+
+    Ok(antinodes.len().into())
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let input = Input::new(input)?;
+    let mut antinodes = HashSet::new();
+
+    for positions in input.antennas.values() {
+        for i in 0..positions.len() {
+            let pos = positions[i];
+
+            for (j, &other) in positions.iter().enumerate() {
+                if j == i {
+                    continue;
+                }
+
+                let mut k = 0;
+
+                loop {
+                    let antinode = other + (other - pos) * k;
+
+                    if input.map.at(antinode).is_none() {
+                        break;
+                    }
+
+                    antinodes.insert(antinode);
+                    k += 1;
+                }
+            }
+        }
+    }
+
+    Ok(antinodes.len().into())
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        Input::new(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+
+    #[test]
+    fn day8() {
+        let input = test_input(8);
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(228)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(766)));
+    }
+}
@@ -0,0 +1,425 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::render::{self, Rgb};
+use crate::visualize::{Frame, Frames, Visualize};
+use crate::Solution;
+
+const DAY: u32 = 9;
+
+fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    let l = parse::single_line(DAY, input)?;
+
+    l.chars()
+        .map(|(c, s)| c.to_digit(10).ok_or_else(|| l.error(s, "expected a digit")))
+        .collect()
+}
+
+fn checksum(input: &[Option<usize>]) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| (*v).map(|v| i * v))
+        .sum()
+}
+
+pub fn solve_part1(raw_input: &str) -> Result<Answer, ParseError> {
+    let mut input = vec![];
+
+    // Parse input
+    for (idx, c) in parse_input(raw_input)?.into_iter().enumerate() {
+        if idx % 2 == 0 {
+            let id = idx / 2;
+            // File block
+            for _ in 0..c {
+                input.push(Some(id));
+            }
+        } else {
+            // Free space block
+            for _ in 0..c {
+                input.push(None);
+            }
+        }
+    }
+
+    // Helpers
+    let next_free = |l: &mut Vec<Option<usize>>, start_idx: usize| {
+        (start_idx..l.len()).find(|&i| l[i].is_none())
+    };
+
+    let last_used = |l: &mut Vec<Option<usize>>, start_idx: usize| {
+        (0..=start_idx).rev().find(|&i| l[i].is_some())
+    };
+
+    // Swap blocks. There may be no free block at all, or no file block
+    let l_len = input.len();
+    let mut next_free_idx = next_free(&mut input, 0).unwrap_or(l_len);
+    let mut last_used_idx = match l_len {
+        0 => 0,
+        _ => last_used(&mut input, l_len - 1).unwrap_or(0),
+    };
+
+    while next_free_idx < last_used_idx {
+        crate::debug!(
+            "move a block of file {} from {last_used_idx} to {next_free_idx}",
+            input[last_used_idx].unwrap()
+        );
+        input[next_free_idx] = input[last_used_idx];
+        input[last_used_idx] = None;
+
+        next_free_idx = next_free(&mut input, next_free_idx).unwrap();
+        last_used_idx = last_used(&mut input, last_used_idx).unwrap();
+    }
+
+    // Compute checksum
+    Ok(checksum(&input).into())
+}
+
+#[derive(Debug)]
+enum Block {
+    File { id: i32, size: usize },
+    Empty { size: usize },
+}
+
+/// Disk being compacted a whole file at a time, as in part 2.
+struct Disk {
+    blocks: Vec<Block>,
+    next_id_to_move: i32,
+}
+
+impl Disk {
+    fn new(sizes: &[u32]) -> Self {
+        let mut blocks = vec![];
+        let mut next_id_to_move = -1;
+
+        // Track the highest block id. This is the first to be moved
+        for (idx, &size) in sizes.iter().enumerate() {
+            if idx % 2 == 0 {
+                let id = (idx as i32) / 2;
+
+                blocks.push(Block::File {
+                    id,
+                    size: size as _,
+                });
+
+                next_id_to_move = id;
+            } else if size > 0 {
+                blocks.push(Block::Empty { size: size as _ });
+            }
+        }
+
+        Self {
+            blocks,
+            next_id_to_move,
+        }
+    }
+
+    /// Tries to move the next file, returning false once all files have been
+    /// tried.
+    fn step(&mut self) -> bool {
+        if self.next_id_to_move < 0 {
+            return false;
+        }
+
+        let input = &mut self.blocks;
+
+        // Find next block to move
+        let (idx_to_move, id_to_move, size_to_move) = input
+            .iter()
+            .enumerate()
+            .find_map(|(idx, entry)| match entry {
+                Block::File { id, size } => {
+                    if *id == self.next_id_to_move {
+                        Some((idx, *id, *size))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })
+            .unwrap();
+
+        // Find empty space, from right to left
+        let mut free_block = None;
+
+        for i in (0..idx_to_move).rev() {
+            let Block::Empty { size } = input[i] else {
+                continue;
+            };
+
+            if size >= size_to_move {
+                free_block = Some((i, size));
+            }
+        }
+
+        // Move block to available place
+        if let Some((free_idx, free_size)) = free_block {
+            crate::debug!("move file {id_to_move} of {size_to_move} blocks to the left");
+            let remaining_free_size = free_size - size_to_move;
+
+            input[free_idx] = Block::File {
+                id: id_to_move,
+                size: size_to_move,
+            };
+
+            input[idx_to_move] = Block::Empty { size: size_to_move };
+
+            if remaining_free_size > 0 {
+                input.insert(
+                    free_idx + 1,
+                    Block::Empty {
+                        size: remaining_free_size,
+                    },
+                );
+            }
+        }
+
+        // Merge possible consecutive empty blocks
+        loop {
+            let mut merged = false;
+
+            for i in 0..(input.len() - 1) {
+                let (Block::Empty { size: size_i }, Block::Empty { size: size_i1 }) =
+                    (&input[i], &input[i + 1])
+                else {
+                    continue;
+                };
+
+                input[i] = Block::Empty {
+                    size: size_i + size_i1,
+                };
+
+                input.remove(i + 1);
+
+                merged = true;
+                break;
+            }
+
+            if !merged {
+                break;
+            }
+        }
+
+        self.next_id_to_move -= 1;
+
+        true
+    }
+
+    /// Blocks with the same layout than in part 1.
+    fn layout(&self) -> Vec<Option<usize>> {
+        let mut layout = vec![];
+
+        for block in &self.blocks {
+            match *block {
+                Block::File { id, size } => {
+                    layout.extend(std::iter::repeat_n(Some(id as usize), size))
+                }
+                Block::Empty { size } => layout.extend(std::iter::repeat_n(None, size)),
+            }
+        }
+
+        layout
+    }
+}
+
+pub fn solve_part2(raw_input: &str) -> Result<Answer, ParseError> {
+    let mut disk = Disk::new(&parse_input(raw_input)?);
+
+    // Try to move all blocks
+    while disk.step() {}
+
+    Ok(checksum(&disk.layout()).into())
+}
+
+/// Symbols of the files, which are reused when there are more files.
+const FILE_SYMBOLS: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Blocks per row of a frame.
+const DISK_WIDTH: usize = 100;
+
+fn render(layout: &[Option<usize>]) -> Grid<char> {
+    let symbols: Vec<char> = FILE_SYMBOLS.chars().collect();
+    let mut cells: Vec<char> = layout
+        .iter()
+        .map(|block| block.map_or('.', |id| symbols[id % symbols.len()]))
+        .collect();
+
+    let width = DISK_WIDTH.min(cells.len()).max(1);
+    let height = cells.len().div_ceil(width).max(1);
+    cells.resize(width * height, ' ');
+
+    Grid::from_vec(width, height, cells)
+}
+
+/// Disk layout during the compaction of part 2, one frame per file tried.
+fn disk_frames(input: &str) -> Result<Frames<'static>, ParseError> {
+    let mut disk = Disk::new(&parse_input(input)?);
+    let mut done = false;
+
+    Ok(Box::new(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        let frame = Frame {
+            grid: render(&disk.layout()),
+            caption: match disk.next_id_to_move {
+                -1 => "compacted".to_string(),
+                id => format!("next file to move: {id}"),
+            },
+        };
+        done = !disk.step();
+
+        Some(frame)
+    })))
+}
+
+pub struct Day9;
+
+impl Visualize for Day9 {
+    fn frames<'a>(&self, input: &'a str) -> Result<Frames<'a>, ParseError> {
+        disk_frames(input)
+    }
+
+    fn color(&self, c: char) -> Rgb {
+        // Neighbouring files get far apart colours
+        match FILE_SYMBOLS.find(c) {
+            Some(idx) => render::hue(idx * 23, FILE_SYMBOLS.len()),
+            None => render::color(c),
+        }
+    }
+}
+
+impl Solution for Day9 {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{test_input, test_named_input};
+    use crate::rng::Rng;
+
+    #[test]
+    fn day9() {
+        let input = test_input(9);
+        let example = test_named_input(9, "example");
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(6241633730082)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(6265268809555)));
+
+        let frames: Vec<_> = disk_frames(&example).unwrap().collect();
+        assert_eq!(frames.len(), 11);
+        assert_eq!(
+            frames[0].grid.row(0).unwrap().collect::<String>(),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+        assert_eq!(
+            frames[10].grid.row(0).unwrap().collect::<String>(),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+        assert_eq!(frames[10].caption, "compacted");
+    }
+
+    /// A random disk map, starting and ending with a file.
+    fn generate(rng: &mut Rng) -> String {
+        let len = rng.range(0..15) * 2 + 1;
+
+        let digits: String = (0..len)
+            .map(|idx| match idx % 2 {
+                0 => rng.range(1..10),
+                _ => rng.range(0..10),
+            })
+            .map(|d| char::from(b'0' + d as u8))
+            .collect();
+
+        digits + "\n"
+    }
+
+    fn expand(input: &str) -> Vec<Option<usize>> {
+        let mut blocks = vec![];
+
+        for (idx, c) in input.trim().chars().enumerate() {
+            let id = (idx % 2 == 0).then_some(idx / 2);
+            blocks.extend(std::iter::repeat_n(id, c.to_digit(10).unwrap() as usize));
+        }
+
+        blocks
+    }
+
+    /// Moves the last file block to the first free block, until there is
+    /// no gap left.
+    fn reference_part1(input: &str) -> Answer {
+        let mut blocks = expand(input);
+
+        loop {
+            let first_free = blocks.iter().position(Option::is_none);
+            let last_used = blocks.iter().rposition(Option::is_some);
+
+            match (first_free, last_used) {
+                (Some(free), Some(used)) if free < used => blocks.swap(free, used),
+                _ => break,
+            }
+        }
+
+        checksum(&blocks).into()
+    }
+
+    /// Moves each file, by decreasing id, to the leftmost span of free blocks
+    /// that fits it.
+    fn reference_part2(input: &str) -> Answer {
+        let mut blocks = expand(input);
+        let files = blocks.iter().flatten().max().map_or(0, |id| id + 1);
+
+        for id in (0..files).rev() {
+            let start = blocks.iter().position(|&b| b == Some(id)).unwrap();
+            let size = blocks.iter().filter(|&&b| b == Some(id)).count();
+
+            let free = (0..start)
+                .take_while(|i| i + size <= start)
+                .find(|&i| blocks[i..i + size].iter().all(Option::is_none));
+
+            if let Some(free) = free {
+                for i in 0..size {
+                    blocks.swap(free + i, start + i);
+                }
+            }
+        }
+
+        checksum(&blocks).into()
+    }
+
+    #[test]
+    fn properties() {
+        let mut rng = Rng::new(9);
+
+        for _ in 0..300 {
+            let input = generate(&mut rng);
+
+            assert_eq!(solve_part1(&input), Ok(reference_part1(&input)), "{input}");
+            assert_eq!(solve_part2(&input), Ok(reference_part2(&input)), "{input}");
+        }
+    }
+}
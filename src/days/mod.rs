@@ -0,0 +1,92 @@
+use crate::Solution;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day13;
+pub mod day14;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Every solved day, in calendar order.
+pub static DAYS: &[&dyn Solution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day13::Day13,
+    &day14::Day14,
+];
+
+/// Looks up the solver of a given day.
+pub fn get(day: u32) -> Option<&'static dyn Solution> {
+    DAYS.iter().copied().find(|s| s.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{test_inputs, DEFAULT_INPUT_NAME};
+    use crate::verify::{self, Answers};
+    use std::path::Path;
+
+    /// Runs both parts of every day on each of its example inputs, the
+    /// inputs other than the puzzle one, and compares them to the answers
+    /// recorded in `answers.toml`. Empty inputs are skipped, so that a new
+    /// day can be scaffolded before its example is filled in.
+    #[test]
+    fn examples() {
+        let inputs = test_inputs();
+        let answers = Answers::load(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join(verify::DEFAULT_ANSWERS_FILE),
+        )
+        .unwrap();
+        let mut checked = 0;
+
+        for solution in DAYS {
+            let day = solution.day();
+
+            for name in inputs.names(day) {
+                let input = inputs.read_named(day, &name).unwrap();
+                if name == DEFAULT_INPUT_NAME || input.is_empty() {
+                    continue;
+                }
+
+                let expected = [1, 2].map(|part| answers.get(day, &name, part));
+                assert!(
+                    expected.iter().any(Option::is_some),
+                    "day {day}: no answers recorded for '{name}'"
+                );
+
+                for (part, expected) in (1..=2).zip(expected) {
+                    let Some(expected) = expected else {
+                        continue;
+                    };
+
+                    let answer = solution.part_with(part, &input, &answers.params(day, &name));
+                    assert_eq!(
+                        answer.map(|a| a.to_string()).as_deref(),
+                        Ok(expected),
+                        "day {day}, '{name}', part {part}"
+                    );
+                    checked += 1;
+                }
+            }
+        }
+
+        assert!(checked >= 2 * DAYS.len());
+    }
+}
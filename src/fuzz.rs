@@ -0,0 +1,347 @@
+//! Fuzzing of the parsers, and optionally the solvers, with a seeded mutator.
+//!
+//! Inputs are derived from a corpus, usually the inputs of the day, by a few
+//! random mutations each. A parser may accept or reject them, but it must
+//! neither panic nor hang. When solving, both parts also run on the inputs
+//! the parser accepts, and may fail with an error but neither panic nor
+//! hang. Solving is not the default, as some parts take seconds on a full
+//! input and would be reported as hanging.
+
+use std::any::Any;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::input;
+use crate::rng::Rng;
+use crate::{Params, Solution};
+
+/// Pieces of the puzzle syntaxes, inserted to get past the first checks of
+/// the parsers.
+const TOKENS: &[&str] = &[
+    "mul(",
+    "do()",
+    "don't()",
+    ",",
+    ", ",
+    ")",
+    "|",
+    ": ",
+    " ",
+    "\n",
+    "\n\n",
+    "-",
+    "+",
+    "=",
+    "#",
+    ".",
+    "^",
+    "0",
+    "9",
+    "99999999999999999999",
+    "Button A: X+",
+    "Button B: X+",
+    ", Y+",
+    "Prize: X=",
+    ", Y=",
+    "p=",
+    " v=",
+];
+
+/// Derives inputs from a corpus. The same seed and corpus always give the
+/// same inputs.
+pub struct Mutator {
+    rng: Rng,
+    corpus: Vec<Vec<u8>>,
+}
+
+impl Mutator {
+    pub fn new(seed: u64, corpus: Vec<String>) -> Self {
+        let mut corpus: Vec<_> = corpus.into_iter().map(String::into_bytes).collect();
+        if corpus.is_empty() {
+            corpus.push(vec![]);
+        }
+
+        Self {
+            rng: Rng::new(seed),
+            corpus,
+        }
+    }
+
+    /// A random range of `len` bytes, possibly empty.
+    fn span(&mut self, len: usize) -> (usize, usize) {
+        let start = self.rng.index(len + 1);
+        let end = start + self.rng.index(len - start + 1).min(16);
+
+        (start, end)
+    }
+
+    fn mutate(&mut self, s: &mut Vec<u8>) {
+        let at = self.rng.index(s.len() + 1);
+
+        match self.rng.range(0..7) {
+            // Byte flip
+            0 if !s.is_empty() => {
+                let idx = at.min(s.len() - 1);
+                s[idx] ^= 1 << self.rng.range(0..8);
+            }
+            // Random byte, mostly ASCII
+            1 => {
+                let b = match self.rng.chance(0.9) {
+                    true => self.rng.range(0x20..0x7f) as u8,
+                    false => self.rng.range(0..256) as u8,
+                };
+                s.insert(at, b);
+            }
+            2 => {
+                let token = self.rng.choose(TOKENS).as_bytes();
+                s.splice(at..at, token.iter().copied());
+            }
+            3 => {
+                let (start, end) = self.span(s.len());
+                s.drain(start..end);
+            }
+            4 => {
+                let (start, end) = self.span(s.len());
+                let copy = s[start..end].to_vec();
+                s.splice(end..end, copy);
+            }
+            5 => s.truncate(at),
+            // Splice with another input of the corpus
+            _ => {
+                let other = self.rng.choose(&self.corpus);
+                let from = self.rng.index(other.len() + 1);
+                let tail = other[from..].to_vec();
+                s.truncate(at);
+                s.extend(tail);
+            }
+        }
+    }
+
+    /// The next input, normalized like inputs read by the runner.
+    pub fn next_input(&mut self) -> String {
+        let mut s = self.rng.choose(&self.corpus).clone();
+
+        for _ in 0..self.rng.range(1..5) {
+            self.mutate(&mut s);
+        }
+
+        input::normalize(&String::from_utf8_lossy(&s))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Accepted,
+    Rejected,
+    Panicked(String),
+    TimedOut,
+}
+
+/// Message given to `panic!`, from the payload of the panic.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Parses an input on its own thread, and runs both parts on it when
+/// `solve` is set and the parser accepts it. A thread that times out is left
+/// running, since it cannot be stopped.
+pub fn check(
+    solution: &'static dyn Solution,
+    input: String,
+    timeout: Duration,
+    solve: bool,
+) -> Outcome {
+    let (tx, rx) = mpsc::channel();
+
+    // Part running on the thread, 0 while parsing
+    let part = Arc::new(AtomicU32::new(0));
+    let running = Arc::clone(&part);
+
+    let handle = thread::spawn(move || {
+        let accepted = solution.parse(&input).is_ok();
+
+        if accepted && solve {
+            for p in 1..=2 {
+                running.store(p, Ordering::Relaxed);
+                let _ = solution.part_with(p, &input, &Params::new());
+            }
+        }
+
+        tx.send(accepted).unwrap();
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(true) => Outcome::Accepted,
+        Ok(false) => Outcome::Rejected,
+        Err(RecvTimeoutError::Disconnected) => {
+            let message = handle.join().err().map_or_else(String::new, panic_message);
+
+            Outcome::Panicked(match part.load(Ordering::Relaxed) {
+                0 => message,
+                p => format!("part {p}: {message}"),
+            })
+        }
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+    }
+}
+
+/// An input that made a parser or a part panic or hang.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Finding {
+    pub day: u32,
+    pub input: String,
+    pub outcome: Outcome,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub accepted: usize,
+    pub rejected: usize,
+    pub findings: Vec<Finding>,
+}
+
+/// Parses `iterations` mutated inputs, and solves those accepted when
+/// `solve` is set. Stops at the first hang, which keeps a thread busy.
+pub fn fuzz(
+    solution: &'static dyn Solution,
+    corpus: Vec<String>,
+    seed: u64,
+    iterations: usize,
+    timeout: Duration,
+    solve: bool,
+) -> Summary {
+    let mut mutator = Mutator::new(seed, corpus);
+    let mut summary = Summary::default();
+
+    for _ in 0..iterations {
+        let input = mutator.next_input();
+
+        match check(solution, input.clone(), timeout, solve) {
+            Outcome::Accepted => summary.accepted += 1,
+            Outcome::Rejected => summary.rejected += 1,
+            outcome => {
+                let hung = outcome == Outcome::TimedOut;
+                summary.findings.push(Finding {
+                    day: solution.day(),
+                    input,
+                    outcome,
+                });
+
+                if hung {
+                    break;
+                }
+            }
+        }
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::days;
+    use crate::input::test_inputs;
+    use crate::parse::{self, ParseError};
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        fn day(&self) -> u32 {
+            0
+        }
+
+        fn title(&self) -> &'static str {
+            "Fragile"
+        }
+
+        fn parse(&self, input: &str) -> Result<(), ParseError> {
+            match input {
+                "panic" => panic!("not today"),
+                "hang" => thread::sleep(Duration::from_millis(200)),
+                _ => parse::single_line(0, input).map(|_| ())?,
+            }
+
+            Ok(())
+        }
+
+        fn part1(&self, _input: &str) -> Result<Answer, ParseError> {
+            Ok(Answer::Int(0))
+        }
+
+        fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+            match input {
+                "boom\n" => panic!("too far"),
+                _ => Ok(Answer::Int(0)),
+            }
+        }
+    }
+
+    #[test]
+    fn parsers() {
+        let timeout = Duration::from_millis(50);
+        assert_eq!(
+            check(&Fragile, "ok\n".into(), timeout, false),
+            Outcome::Accepted
+        );
+        assert_eq!(
+            check(&Fragile, "a\nb\n".into(), timeout, false),
+            Outcome::Rejected
+        );
+        assert_eq!(
+            check(&Fragile, "panic".into(), timeout, false),
+            Outcome::Panicked("not today".to_string())
+        );
+        assert_eq!(
+            check(&Fragile, "hang".into(), timeout, false),
+            Outcome::TimedOut
+        );
+        assert_eq!(
+            check(&Fragile, "boom\n".into(), timeout, false),
+            Outcome::Accepted
+        );
+        assert_eq!(
+            check(&Fragile, "boom\n".into(), timeout, true),
+            Outcome::Panicked("part 2: too far".to_string())
+        );
+
+        let mut mutator = Mutator::new(1, vec!["abc".to_string()]);
+        let inputs: Vec<_> = (0..3).map(|_| mutator.next_input()).collect();
+        let mut mutator = Mutator::new(1, vec!["abc".to_string()]);
+        assert_eq!(
+            inputs,
+            (0..3).map(|_| mutator.next_input()).collect::<Vec<_>>()
+        );
+
+        let inputs = test_inputs();
+
+        for &solution in days::DAYS {
+            let day = solution.day();
+            let corpus = inputs
+                .names(day)
+                .iter()
+                .map(|name| inputs.read_named(day, name).unwrap())
+                .collect();
+
+            let summary = fuzz(
+                solution,
+                corpus,
+                day as u64,
+                300,
+                Duration::from_secs(5),
+                false,
+            );
+            assert_eq!(summary.findings, vec![], "day {day}");
+        }
+    }
+}
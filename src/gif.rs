@@ -0,0 +1,290 @@
+//! Animated GIF encoder, just enough for the frames of the simulations.
+//!
+//! Every frame has its own colour table, so frames are limited to 256
+//! colours each but not overall.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::render::{Image, Rgb};
+
+const MAX_CODE_SIZE: u32 = 12;
+
+/// Writes the codes of the LZW compression, least significant bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            bytes: vec![],
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    fn write(&mut self, code: u16, size: u32) {
+        self.acc |= (code as u32) << self.bits;
+        self.bits += size;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+
+        self.bytes
+    }
+}
+
+/// Compresses colour indices with the variable-length LZW flavour of GIF.
+fn lzw(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut out = BitWriter::new();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end + 1;
+    let mut code_size = min_code_size + 1;
+
+    out.write(clear, code_size);
+
+    let Some((&first, rest)) = indices.split_first() else {
+        out.write(end, code_size);
+        return out.finish();
+    };
+
+    let mut prefix = first as u16;
+
+    for &idx in rest {
+        if let Some(&code) = table.get(&(prefix, idx)) {
+            prefix = code;
+            continue;
+        }
+
+        out.write(prefix, code_size);
+
+        if next_code < 1 << MAX_CODE_SIZE {
+            table.insert((prefix, idx), next_code);
+            // The decoder grows its codes one entry later than the encoder
+            if next_code == 1 << code_size {
+                code_size += 1;
+            }
+            next_code += 1;
+        } else {
+            out.write(clear, code_size);
+            table.clear();
+            next_code = end + 1;
+            code_size = min_code_size + 1;
+        }
+
+        prefix = idx as u16;
+    }
+
+    out.write(prefix, code_size);
+    out.write(end, code_size);
+    out.finish()
+}
+
+pub struct GifEncoder<W: Write> {
+    out: W,
+    width: u16,
+    height: u16,
+    /// Delay between frames, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> GifEncoder<W> {
+    /// Writes the header of an animation looping forever.
+    pub fn new(mut out: W, width: usize, height: usize, fps: f64) -> io::Result<Self> {
+        let size = |v: usize| {
+            u16::try_from(v)
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "image too large"))
+        };
+        let (width, height) = (size(width)?, size(height)?);
+        let delay = (100.0 / fps).round().clamp(1.0, u16::MAX as f64) as u16;
+
+        out.write_all(b"GIF89a")?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        // No global colour table, background colour and aspect ratio unused
+        out.write_all(&[0, 0, 0])?;
+
+        // Loop forever
+        out.write_all(&[0x21, 0xff, 11])?;
+        out.write_all(b"NETSCAPE2.0")?;
+        out.write_all(&[3, 1, 0, 0, 0])?;
+
+        Ok(Self {
+            out,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub fn add_frame(&mut self, image: &Image) -> io::Result<()> {
+        if (image.width, image.height) != (self.width as usize, self.height as usize) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frames must all have the same size",
+            ));
+        }
+
+        let mut colors: Vec<Rgb> = vec![];
+        let mut lookup = HashMap::new();
+        let mut indices = Vec::with_capacity(image.pixels.len());
+
+        for &pixel in &image.pixels {
+            let idx = *lookup.entry(pixel).or_insert_with(|| {
+                colors.push(pixel);
+                colors.len() - 1
+            });
+
+            indices.push(u8::try_from(idx).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "more than 256 colours in a frame",
+                )
+            })?);
+        }
+
+        // The table has 2^(n + 1) entries, and LZW codes start at 2 bits
+        let table_bits = (usize::BITS - (colors.len().max(2) - 1).leading_zeros()).max(1);
+        colors.resize(1 << table_bits, [0, 0, 0]);
+
+        // Graphic control extension, for the delay
+        self.out.write_all(&[0x21, 0xf9, 4, 0])?;
+        self.out.write_all(&self.delay.to_le_bytes())?;
+        self.out.write_all(&[0, 0])?;
+
+        // Image descriptor, with a local colour table
+        self.out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&self.width.to_le_bytes())?;
+        self.out.write_all(&self.height.to_le_bytes())?;
+        self.out.write_all(&[0x80 | (table_bits - 1) as u8])?;
+        self.out.write_all(&colors.concat())?;
+
+        let min_code_size = table_bits.max(2);
+        self.out.write_all(&[min_code_size as u8])?;
+
+        for block in lzw(&indices, min_code_size).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+
+        self.out.write_all(&[0])
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()?;
+
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reference decoder, to check that the codes round-trip.
+    fn unlzw(data: &[u8], min_code_size: u32) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let end = clear + 1;
+
+        let mut table: Vec<Vec<u8>> = vec![];
+        let reset = |table: &mut Vec<Vec<u8>>| {
+            *table = (0..clear).map(|i| vec![i as u8]).collect();
+            table.extend([vec![], vec![]]);
+        };
+        reset(&mut table);
+
+        let mut code_size = min_code_size + 1;
+        let (mut acc, mut bits) = (0u32, 0u32);
+        let mut prev: Option<Vec<u8>> = None;
+        let mut out = vec![];
+
+        for &byte in data {
+            acc |= (byte as u32) << bits;
+            bits += 8;
+
+            while bits >= code_size {
+                let code = (acc & ((1 << code_size) - 1)) as usize;
+                acc >>= code_size;
+                bits -= code_size;
+
+                if code == clear {
+                    reset(&mut table);
+                    code_size = min_code_size + 1;
+                    prev = None;
+                    continue;
+                }
+                if code == end {
+                    return out;
+                }
+
+                let entry = match (&prev, table.get(code)) {
+                    (_, Some(entry)) => entry.clone(),
+                    (Some(p), None) => [p.as_slice(), &p[..1]].concat(),
+                    (None, None) => panic!("invalid code {code}"),
+                };
+
+                if let Some(p) = prev {
+                    if table.len() < 1 << MAX_CODE_SIZE {
+                        table.push([p.as_slice(), &entry[..1]].concat());
+                    }
+                }
+                if table.len() == 1 << code_size && code_size < MAX_CODE_SIZE {
+                    code_size += 1;
+                }
+
+                out.extend(&entry);
+                prev = Some(entry);
+            }
+        }
+
+        out
+    }
+
+    #[test]
+    fn gif() {
+        // Long enough to fill the table and force a clear code
+        let indices: Vec<u8> = (0..20_000u32)
+            .map(|i| ((i * i / 7 + i / 3) % 4) as u8)
+            .collect();
+        assert_eq!(unlzw(&lzw(&indices, 2), 2), indices);
+        assert_eq!(unlzw(&lzw(&[1, 1, 1, 1, 1], 2), 2), vec![1; 5]);
+        assert_eq!(unlzw(&lzw(&[], 2), 2), Vec::<u8>::new());
+
+        let image = Image {
+            width: 2,
+            height: 2,
+            pixels: vec![[0, 0, 0], [255, 0, 0], [255, 0, 0], [0, 0, 255]],
+        };
+        let mut encoder = GifEncoder::new(vec![], 2, 2, 10.0).unwrap();
+        encoder.add_frame(&image).unwrap();
+        encoder.add_frame(&image).unwrap();
+        let bytes = encoder.finish().unwrap();
+
+        assert!(bytes.starts_with(b"GIF89a\x02\x00\x02\x00"));
+        assert_eq!(bytes.last(), Some(&0x3b));
+        // Two frames, each with a 10 hundredths of a second delay
+        assert_eq!(
+            bytes
+                .windows(6)
+                .filter(|w| w == &[0x21, 0xf9, 4, 0, 10, 0])
+                .count(),
+            2
+        );
+    }
+}
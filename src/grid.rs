@@ -0,0 +1,203 @@
+//! Rectangular 2D grid with flat storage.
+
+use crate::geometry::{Direction4, Direction8, Point, Vector};
+use crate::parse::{self, ParseError};
+
+/// A grid of `width * height` cells, addressed with `(x, y)` coordinates
+/// where `(0, 0)` is the top left corner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells, stored row by row.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height);
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(width, height, vec![value; width * height])
+    }
+
+    /// Parses a rectangular character map. `f` maps each character to a
+    /// cell, `None` being reported as an unexpected character.
+    pub fn parse(
+        day: u32,
+        input: &str,
+        mut f: impl FnMut((i32, i32), char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines = parse::map_lines(day, input)?;
+        let height = lines.len();
+        let width = lines[0].text.chars().count();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, l) in lines.iter().enumerate() {
+            for (x, (c, s)) in l.chars().enumerate() {
+                let cell =
+                    f((x as i32, y as i32), c).ok_or_else(|| l.error(s, "unexpected character"))?;
+
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self::from_vec(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        0 <= x && (x as usize) < self.width && 0 <= y && (y as usize) < self.height
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        if self.contains(x, y) {
+            Some(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.index(x, y).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.index(x, y).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn at(&self, p: Point) -> Option<&T> {
+        self.get(p.x, p.y)
+    }
+
+    pub fn at_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.get_mut(p.x, p.y)
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, v)| (((idx % width) as i32, (idx / width) as i32), v))
+    }
+
+    fn neighbours(
+        &self,
+        x: i32,
+        y: i32,
+        offsets: impl Iterator<Item = Vector>,
+    ) -> impl Iterator<Item = ((i32, i32), &T)> {
+        offsets.filter_map(move |v| {
+            let (nx, ny) = (x + v.x, y + v.y);
+            self.get(nx, ny).map(|cell| ((nx, ny), cell))
+        })
+    }
+
+    /// Orthogonal neighbours inside the grid, clockwise from the top.
+    pub fn neighbours4(&self, x: i32, y: i32) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.neighbours(x, y, Direction4::ALL.into_iter().map(Direction4::vector))
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid, clockwise from the
+    /// top.
+    pub fn neighbours8(&self, x: i32, y: i32) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.neighbours(x, y, Direction8::ALL.into_iter().map(Direction8::vector))
+    }
+
+    /// Cells from `(x, y)` included, moving by `(dx, dy)` until leaving the grid.
+    /// There are none when `(dx, dy)` is `(0, 0)`, as the ray would not end.
+    pub fn ray(&self, x: i32, y: i32, dx: i32, dy: i32) -> impl Iterator<Item = &T> {
+        (0..)
+            .take_while(move |_| (dx, dy) != (0, 0))
+            .map(move |i| self.get(x + i * dx, y + i * dy))
+            .take_while(Option::is_some)
+            .flatten()
+    }
+
+    /// Cells of row `y` from left to right, or `None` outside the grid.
+    pub fn row(&self, y: usize) -> Option<impl Iterator<Item = &T>> {
+        (y < self.height).then(|| self.cells[y * self.width..(y + 1) * self.width].iter())
+    }
+
+    /// Cells of column `x` from top to bottom, or `None` outside the grid.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells[x..].iter().step_by(self.width))
+    }
+
+    /// Cells going down and right from `(x, y)`.
+    pub fn diagonal(&self, x: i32, y: i32) -> impl Iterator<Item = &T> {
+        self.ray(x, y, 1, 1)
+    }
+
+    /// Cells going up and right from `(x, y)`.
+    pub fn anti_diagonal(&self, x: i32, y: i32) -> impl Iterator<Item = &T> {
+        self.ray(x, y, 1, -1)
+    }
+}
+
+impl Grid<char> {
+    /// Parses a rectangular character map, keeping every character.
+    pub fn parse_chars(day: u32, input: &str) -> Result<Self, ParseError> {
+        Self::parse(day, input, |_, c| Some(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid() {
+        let mut grid = Grid::parse_chars(0, "abc\ndef").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(grid.get(-1, 0), None);
+
+        *grid.get_mut(1, 1).unwrap() = 'x';
+        assert_eq!(grid.row(1).unwrap().collect::<String>(), "dxf");
+        assert_eq!(grid.column(2).unwrap().collect::<String>(), "cf");
+        assert!(grid.row(2).is_none());
+        assert!(grid.column(3).is_none());
+        assert!(grid.column(7).is_none());
+        assert_eq!(grid.diagonal(0, 0).collect::<String>(), "ax");
+        assert_eq!(grid.anti_diagonal(0, 1).collect::<String>(), "db");
+        assert_eq!(grid.ray(2, 1, -1, 0).collect::<String>(), "fxd");
+        assert_eq!(grid.ray(1, 1, 0, 0).count(), 0);
+
+        let n4: String = grid.neighbours4(0, 0).map(|(_, c)| c).collect();
+        assert_eq!(n4, "bd");
+
+        let n8: Vec<_> = grid.neighbours8(1, 0).map(|(p, _)| p).collect();
+        assert_eq!(n8, vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+
+        let empty: Grid<char> = Grid::new(0, 2, '.');
+        assert_eq!(empty.row(1).unwrap().count(), 0);
+        assert!(empty.column(0).is_none());
+        assert!(empty.row(2).is_none());
+
+        let err = Grid::parse(0, "..\n.#", |_, c| (c == '.').then_some(())).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Inputs directory used when neither `--inputs-dir` nor `AOC_INPUTS` is set.
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+/// Environment variable overriding the inputs directory.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+/// Name of the input used when none is given.
+pub const DEFAULT_INPUT_NAME: &str = "input";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Builds a source from a command line argument, `-` meaning stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(arg.into())
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing {
+        day: u32,
+        path: PathBuf,
+    },
+    Io {
+        source: InputSource,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { day, path } => write!(
+                f,
+                "No input for day {day}: '{}' does not exist (use --input or --inputs-dir)",
+                path.display()
+            ),
+            InputError::Io {
+                source: InputSource::File(path),
+                error,
+            } => write!(f, "Cannot read '{}': {error}", path.display()),
+            InputError::Io {
+                source: InputSource::Stdin,
+                error,
+            } => write!(f, "Cannot read stdin: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Directory holding the puzzle inputs, laid out as `dayNN/<name>.txt`.
+#[derive(Clone, Debug)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Uses `AOC_INPUTS` if set, `inputs` otherwise.
+    pub fn from_env() -> Self {
+        let dir = std::env::var_os(INPUTS_DIR_VAR).unwrap_or(DEFAULT_INPUTS_DIR.into());
+
+        Self::new(dir)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn day_dir(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("day{day:02}"))
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.named_path(day, DEFAULT_INPUT_NAME)
+    }
+
+    pub fn named_path(&self, day: u32, name: &str) -> PathBuf {
+        self.day_dir(day).join(format!("{name}.txt"))
+    }
+
+    pub fn read(&self, day: u32) -> Result<String, InputError> {
+        self.read_named(day, DEFAULT_INPUT_NAME)
+    }
+
+    pub fn read_named(&self, day: u32, name: &str) -> Result<String, InputError> {
+        let path = self.named_path(day, name);
+        if !path.exists() {
+            return Err(InputError::Missing { day, path });
+        }
+
+        read(&InputSource::File(path))
+    }
+
+    /// Names of the inputs available for a day, sorted.
+    pub fn names(&self, day: u32) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(self.day_dir(day)) else {
+            return vec![];
+        };
+
+        let mut names: Vec<_> = entries
+            .filter_map(|e| {
+                let path = e.ok()?.path();
+                if path.extension()? != "txt" {
+                    return None;
+                }
+
+                Some(path.file_stem()?.to_str()?.to_string())
+            })
+            .collect();
+
+        names.sort();
+        names
+    }
+}
+
+/// Brings an input to a canonical form, so that it parses the same
+/// whatever editor or platform it comes from: the BOM is removed, line
+/// endings become `\n`, blank lines become empty and trailing blank lines are
+/// dropped. A non-empty result ends with a single `\n`.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut s = String::with_capacity(input.len());
+
+    for l in input.split('\n') {
+        let l = l.trim_end_matches('\r');
+
+        // A lone CR also ends a line
+        for l in l.split('\r') {
+            if !l.trim().is_empty() {
+                s.push_str(l);
+            }
+            s.push('\n');
+        }
+    }
+
+    s.truncate(s.trim_end_matches('\n').len());
+    if !s.is_empty() {
+        s.push('\n');
+    }
+
+    s
+}
+
+/// Reads and normalizes an input.
+pub fn read(source: &InputSource) -> Result<String, InputError> {
+    let r = match source {
+        InputSource::File(path) => std::fs::read_to_string(path),
+        InputSource::Stdin => {
+            let mut s = String::new();
+            io::stdin().read_to_string(&mut s).map(|_| s)
+        }
+    };
+
+    r.map(|s| normalize(&s)).map_err(|error| InputError::Io {
+        source: source.clone(),
+        error,
+    })
+}
+
+/// The inputs bundled with the repository, for unit tests.
+#[cfg(test)]
+pub(crate) fn test_inputs() -> Inputs {
+    Inputs::new(Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_INPUTS_DIR))
+}
+
+/// Reads a bundled puzzle input, for unit tests.
+#[cfg(test)]
+pub(crate) fn test_input(day: u32) -> String {
+    test_inputs().read(day).unwrap()
+}
+
+/// Reads a bundled named input, such as an example, for unit tests.
+#[cfg(test)]
+pub(crate) fn test_named_input(day: u32, name: &str) -> String {
+    test_inputs().read_named(day, name).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inputs() {
+        let inputs = Inputs::new("some/dir");
+        assert_eq!(inputs.path(6), Path::new("some/dir/day06/input.txt"));
+        assert_eq!(
+            inputs.named_path(6, "alice"),
+            Path::new("some/dir/day06/alice.txt")
+        );
+        assert!(inputs.names(6).is_empty());
+
+        let err = inputs.read(6).unwrap_err();
+        assert!(matches!(err, InputError::Missing { day: 6, .. }));
+        assert_eq!(
+            err.to_string(),
+            "No input for day 6: 'some/dir/day06/input.txt' does not exist (use --input or --inputs-dir)"
+        );
+
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("other.txt"),
+            InputSource::File("other.txt".into())
+        );
+
+        assert_eq!(normalize("\u{feff}1 2\r\n3 4\r\n\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("a\rb\n  \nc\n\n\n"), "a\nb\n\nc\n");
+        assert_eq!(normalize("\n \n"), "");
+    }
+}
@@ -0,0 +1,22 @@
+pub mod answer;
+pub mod bench;
+pub mod cli;
+pub mod days;
+pub mod fuzz;
+pub mod geometry;
+pub mod gif;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod parse;
+pub mod pool;
+pub mod render;
+pub mod report;
+pub mod rng;
+mod solution;
+pub mod template;
+pub mod trace;
+pub mod verify;
+pub mod visualize;
+
+pub use solution::{Params, Solution};
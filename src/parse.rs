@@ -0,0 +1,224 @@
+//! Parsing helpers producing located diagnostics instead of panics.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    /// Line of the error, starting at 1.
+    pub line: usize,
+    /// Column of the error in characters, starting at 1.
+    pub column: usize,
+    /// Offending text, empty when something is missing.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error located right after the last character of `input`.
+    pub fn end_of_input(day: u32, input: &str, message: impl Into<String>) -> Self {
+        let last = input.lines().enumerate().last();
+        let (line, column) = match last {
+            Some((idx, l)) => (idx + 1, l.chars().count() + 1),
+            None => (1, 1),
+        };
+
+        Self {
+            day,
+            line,
+            column,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, ": '{}'", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of puzzle input, used to locate errors.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub day: u32,
+    /// Line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Column of `s`, which must be a slice of this line. Anything else is
+    /// reported at the first column.
+    pub fn column(&self, s: &str) -> usize {
+        let begin = self.text.as_ptr() as usize;
+        let ptr = s.as_ptr() as usize;
+
+        if ptr < begin || ptr > begin + self.text.len() {
+            return 1;
+        }
+
+        self.text[..ptr - begin].chars().count() + 1
+    }
+
+    /// Empty slice located after the last character of the line.
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    /// Characters of the line, along with the slice they span to locate errors.
+    pub fn chars(&self) -> impl Iterator<Item = (char, &'a str)> {
+        let text = self.text;
+
+        text.char_indices()
+            .map(move |(idx, c)| (c, &text[idx..idx + c.len_utf8()]))
+    }
+
+    pub fn error(&self, at: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.column(at),
+            text: at.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn parse<T>(&self, s: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        s.parse().map_err(|e: T::Err| self.error(s, e.to_string()))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("expected '{delimiter}'")))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected '{prefix}'")))
+    }
+}
+
+pub fn lines(day: u32, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(idx, text)| Line {
+        day,
+        number: idx + 1,
+        text,
+    })
+}
+
+/// Lines grouped in paragraphs, which are separated by one or more empty
+/// lines.
+pub fn paragraphs(day: u32, input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut paragraphs = vec![];
+    let mut current = vec![];
+
+    for l in lines(day, input) {
+        if !l.text.is_empty() {
+            current.push(l);
+        } else if !current.is_empty() {
+            paragraphs.push(std::mem::take(&mut current));
+        }
+    }
+
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    paragraphs
+}
+
+/// The only line of a single-line input.
+pub fn single_line(day: u32, input: &str) -> Result<Line<'_>, ParseError> {
+    let mut lines = lines(day, input);
+
+    let Some(first) = lines.next() else {
+        return Err(ParseError::end_of_input(day, input, "empty input"));
+    };
+
+    if let Some(l) = lines.next() {
+        return Err(l.error(l.text, "expected a single line"));
+    }
+
+    Ok(first)
+}
+
+/// Lines of a rectangular character map, rejecting empty or ragged input.
+pub fn map_lines(day: u32, input: &str) -> Result<Vec<Line<'_>>, ParseError> {
+    let lines: Vec<_> = lines(day, input).collect();
+
+    let Some(first) = lines.first() else {
+        return Err(ParseError::end_of_input(day, input, "empty map"));
+    };
+
+    let width = first.text.chars().count();
+    if width == 0 {
+        return Err(first.error(first.text, "empty map row"));
+    }
+
+    for l in &lines {
+        let len = l.text.chars().count();
+        if len != width {
+            return Err(l.error(l.end(), format!("expected {width} columns, found {len}")));
+        }
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let l = lines(1, "12 ab\n3").next().unwrap();
+
+        assert_eq!(l.parse::<i32>(&l.text[0..2]), Ok(12));
+
+        let err = l.parse::<i32>(&l.text[3..]).unwrap_err();
+        assert_eq!(err.line, 1);
+        assert_eq!(err.column, 4);
+        assert_eq!(err.text, "ab");
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 1, column 4: invalid digit found in string: 'ab'"
+        );
+
+        let err = l.split_once(l.text, ",").unwrap_err();
+        assert_eq!(err.column, 1);
+        assert_eq!(err.message, "expected ','");
+
+        let err = map_lines(1, "ab\nabc").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+
+        let p = paragraphs(1, "a\nb\n\n\nc\n");
+        assert_eq!(p.len(), 2);
+        assert_eq!((p[1][0].number, p[1][0].text), (5, "c"));
+
+        let err = ParseError::end_of_input(1, "ab\nc", "missing");
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
@@ -0,0 +1,66 @@
+//! A bounded pool of worker threads.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Number of workers used when none is given.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Applies `f` to every item on at most `threads` threads, and returns the
+/// results in the order of the items. Workers pick the next item as soon as
+/// they are done with the previous one.
+pub fn map<T: Sync, R: Send>(items: &[T], threads: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<R>>> = items.iter().map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            s.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(idx) else {
+                    break;
+                };
+
+                let r = f(item);
+                *results[idx].lock().unwrap() = Some(r);
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|r| r.into_inner().unwrap().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    #[test]
+    fn pool() {
+        let items: Vec<u64> = (0..20).collect();
+
+        // Later items finish first, results still come in order
+        let results = map(&items, 4, |&i| {
+            thread::sleep(Duration::from_millis(20 - i));
+            (i * i, thread::current().id())
+        });
+
+        assert_eq!(
+            results.iter().map(|(v, _)| *v).collect::<Vec<_>>(),
+            items.iter().map(|i| i * i).collect::<Vec<_>>()
+        );
+
+        let threads: HashSet<_> = results.iter().map(|(_, id)| *id).collect();
+        assert!(threads.len() <= 4);
+
+        assert_eq!(map(&items, 1, |&i| i + 1)[19], 20);
+        assert!(map(&[] as &[u64], 8, |&i| i).is_empty());
+    }
+}
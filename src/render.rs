@@ -0,0 +1,213 @@
+//! Export of frames as images: PPM and PGM files, or an animated GIF.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::gif::GifEncoder;
+use crate::visualize::Frame;
+
+pub type Rgb = [u8; 3];
+
+/// Colour of a tile in the default palette: digits are shades of grey and
+/// letters go around the colour wheel.
+pub fn color(c: char) -> Rgb {
+    match c {
+        '.' | ' ' => [16, 16, 24],
+        '#' => [128, 128, 128],
+        'X' => [240, 200, 40],
+        '^' | '>' | 'v' | '<' => [230, 40, 40],
+        '0'..='9' => {
+            let v = 60 + (c as u8 - b'0') * 21;
+            [v, v, v]
+        }
+        'a'..='z' => hue(c as usize - 'a' as usize, 26),
+        'A'..='Z' => hue(c as usize - 'A' as usize, 26),
+        _ => [255, 255, 255],
+    }
+}
+
+/// The `i`th of `n` colours spread around the colour wheel.
+pub fn hue(i: usize, n: usize) -> Rgb {
+    let h = (i % n) as f64 * 6.0 / n as f64;
+    let x = 1.0 - (h % 2.0 - 1.0).abs();
+    let (r, g, b) = match h as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+
+    let scale = |v: f64| (40.0 + v * 215.0) as u8;
+    [scale(r), scale(g), scale(b)]
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws each tile as a `scale` by `scale` square.
+    pub fn from_frame(frame: &Frame, color: &dyn Fn(char) -> Rgb, scale: usize) -> Self {
+        let (width, height) = (frame.grid.width() * scale, frame.grid.height() * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+
+        for y in 0..frame.grid.height() {
+            let row: Vec<Rgb> = frame
+                .grid
+                .row(y)
+                .into_iter()
+                .flatten()
+                .flat_map(|&c| std::iter::repeat_n(color(c), scale))
+                .collect();
+
+            for _ in 0..scale {
+                pixels.extend(&row);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Binary PPM, in colour.
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())
+    }
+
+    /// Binary PGM, in shades of grey.
+    pub fn write_pgm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P5\n{} {}\n255\n", self.width, self.height)?;
+
+        let luma: Vec<u8> = self
+            .pixels
+            .iter()
+            .map(|&[r, g, b]| ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8)
+            .collect();
+        out.write_all(&luma)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFormat {
+    Ppm,
+    Pgm,
+    Gif,
+}
+
+impl ImageFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "ppm" => Some(Self::Ppm),
+            "pgm" => Some(Self::Pgm),
+            "gif" => Some(Self::Gif),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RenderOptions {
+    pub format: ImageFormat,
+    /// Side of a tile, in pixels.
+    pub scale: usize,
+    /// Frame rate of an animation.
+    pub fps: f64,
+}
+
+fn create(path: &Path) -> io::Result<BufWriter<File>> {
+    File::create(path).map(BufWriter::new)
+}
+
+/// Writes the frames to `dir`, as one `<name>_<frame>` image per frame or
+/// as a single `<name>.gif`. Returns the files written.
+pub fn export(
+    frames: impl Iterator<Item = (usize, Frame)>,
+    color: &dyn Fn(char) -> Rgb,
+    options: &RenderOptions,
+    dir: &Path,
+    name: &str,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+
+    let mut frames =
+        frames.map(|(idx, frame)| (idx, Image::from_frame(&frame, color, options.scale)));
+
+    if options.format == ImageFormat::Gif {
+        let Some((_, first)) = frames.next() else {
+            return Ok(vec![]);
+        };
+
+        let path = dir.join(format!("{name}.gif"));
+        let mut gif = GifEncoder::new(create(&path)?, first.width, first.height, options.fps)?;
+        gif.add_frame(&first)?;
+        for (_, image) in frames {
+            gif.add_frame(&image)?;
+        }
+        gif.finish()?;
+
+        return Ok(vec![path]);
+    }
+
+    let mut written = vec![];
+
+    for (idx, image) in frames {
+        let path = match options.format {
+            ImageFormat::Pgm => dir.join(format!("{name}_{idx:05}.pgm")),
+            _ => dir.join(format!("{name}_{idx:05}.ppm")),
+        };
+
+        let mut out = create(&path)?;
+        match options.format {
+            ImageFormat::Pgm => image.write_pgm(&mut out)?,
+            _ => image.write_ppm(&mut out)?,
+        }
+        out.flush()?;
+
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn render() {
+        let frame = Frame {
+            grid: Grid::from_vec(2, 1, vec!['#', '.']),
+            caption: String::new(),
+        };
+
+        let image = Image::from_frame(&frame, &color, 2);
+        assert_eq!((image.width, image.height), (4, 2));
+        assert_eq!(image.pixels[1], color('#'));
+        assert_eq!(image.pixels[2], color('.'));
+        assert_eq!(image.pixels[4..], image.pixels[..4]);
+
+        let mut ppm = vec![];
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 2\n255\n\x80\x80\x80"));
+        assert_eq!(ppm.len(), 11 + 4 * 2 * 3);
+
+        let mut pgm = vec![];
+        image.write_pgm(&mut pgm).unwrap();
+        assert_eq!(pgm, b"P5\n4 2\n255\n\x80\x80\x10\x10\x80\x80\x10\x10");
+
+        assert_eq!(hue(0, 6), [255, 40, 40]);
+        assert_eq!(hue(2, 6), [40, 255, 40]);
+        assert_eq!(ImageFormat::parse("gif"), Some(ImageFormat::Gif));
+    }
+}
@@ -0,0 +1,104 @@
+//! Timed results of the parts, for machine-readable output.
+
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::input;
+use crate::json::Json;
+use crate::parse::ParseError;
+use crate::trace;
+use crate::{Params, Solution};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub input_hash: String,
+}
+
+impl PartReport {
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("day", Json::from(self.day)),
+            ("part", Json::from(self.part)),
+            ("answer", self.answer.to_json()),
+            ("elapsed_ns", Json::from(self.elapsed.as_nanos() as u64)),
+            ("input_hash", Json::from(self.input_hash.as_str())),
+        ])
+    }
+}
+
+/// FNV-1a hash of an input, in hexadecimal. It tells which input an answer
+/// was computed from without publishing the input itself.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+/// Runs a part, starting at 1, on an input having `params`, and times it.
+/// The input is normalized first, outside of the timing, so that it does not
+/// matter where it comes from.
+pub fn run_part(
+    solution: &dyn Solution,
+    part: u32,
+    input: &str,
+    params: &Params,
+) -> Result<PartReport, ParseError> {
+    let input = &input::normalize(input);
+    let _day = trace::span(format!("day {}", solution.day()));
+    let _part = trace::span(format!("part {part}"));
+
+    let start = Instant::now();
+    let answer = solution.part_with(part, input, params)?;
+    let elapsed = start.elapsed();
+
+    Ok(PartReport {
+        day: solution.day(),
+        part,
+        answer,
+        elapsed,
+        input_hash: input_hash(input),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+
+        let report = PartReport {
+            day: 11,
+            part: 2,
+            answer: Answer::Int(65601038650482),
+            elapsed: Duration::from_micros(15),
+            input_hash: input_hash("125 17\n"),
+        };
+
+        assert_eq!(
+            report.to_json().to_string(),
+            format!(
+                r#"{{"day":11,"part":2,"answer":65601038650482,"elapsed_ns":15000,"input_hash":"{}"}}"#,
+                input_hash("125 17\n")
+            )
+        );
+
+        let crlf = run_part(
+            &crate::days::day1::Day1,
+            1,
+            "\u{feff}3 4\r\n4 3\r\n\r\n",
+            &Params::new(),
+        )
+        .unwrap();
+        assert_eq!(crlf.answer, Answer::Int(0));
+        assert_eq!(crlf.input_hash, input_hash("3 4\n4 3\n"));
+    }
+}
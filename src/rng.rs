@@ -0,0 +1,69 @@
+//! A small seeded random number generator, so that randomized tests are
+//! reproducible and need nothing beyond the standard library.
+
+use std::ops::Range;
+
+/// SplitMix64, which is fast and good enough for generating test inputs.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(!range.is_empty(), "empty range {range:?}");
+
+        let len = range.end.abs_diff(range.start);
+        range.start.wrapping_add((self.next_u64() % len) as i64)
+    }
+
+    /// An index below `n`, which must not be zero.
+    pub fn index(&mut self, n: usize) -> usize {
+        self.range(0..n as i64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng() {
+        // Reference values of SplitMix64 seeded with 0
+        let mut rng = Rng::new(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+
+        let mut rng = Rng::new(42);
+        let values: Vec<_> = (0..1000).map(|_| rng.range(-3..4)).collect();
+        assert!(values.iter().all(|v| (-3..4).contains(v)));
+        assert!((-3..4).all(|v| values.contains(&v)));
+
+        assert!((0..100).all(|_| rng.chance(1.0) && !rng.chance(0.0)));
+        assert!(rng.range(i64::MIN..i64::MAX) != rng.range(i64::MIN..i64::MAX));
+        assert_eq!(*Rng::new(3).choose(&['a']), 'a');
+    }
+}
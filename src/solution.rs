@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+
+use crate::answer::Answer;
+use crate::parse::ParseError;
+use crate::visualize::Visualize;
+
+/// Settings of an input that are not part of its text, such as the size of
+/// the room of the day 14 example, by name.
+pub type Params = BTreeMap<String, String>;
+
+/// A puzzle solver for one day of the calendar.
+///
+/// Inputs are expected in the form given by [`crate::input::normalize`].
+/// Rather than calling the parts directly, run them with
+/// [`crate::report::run_part`], which normalizes the input first.
+pub trait Solution: Sync {
+    /// Day of the puzzle, starting at 1.
+    fn day(&self) -> u32;
+
+    /// Title of the puzzle.
+    fn title(&self) -> &'static str;
+
+    /// Only parses the input, so that parsing can be checked and timed on its
+    /// own.
+    fn parse(&self, input: &str) -> Result<(), ParseError>;
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError>;
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError>;
+
+    /// Runs a part, starting at 1, on an input having `params`. Solvers
+    /// without settings ignore them.
+    fn part_with(&self, part: u32, input: &str, params: &Params) -> Result<Answer, ParseError> {
+        let _ = params;
+
+        match part {
+            1 => self.part1(input),
+            _ => self.part2(input),
+        }
+    }
+
+    /// Frames of the simulation, for the days having one.
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        None
+    }
+}
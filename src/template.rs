@@ -0,0 +1,269 @@
+//! Scaffolding of a new day.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SOLVER: &str = r#"use crate::answer::Answer;
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+const DAY: u32 = {day};
+
+fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(parse::lines(DAY, input).map(|l| l.text).collect())
+}
+
+pub fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+    let _input = parse_input(input)?;
+
+    Ok(Answer::Int(0))
+}
+
+pub fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+    let _input = parse_input(input)?;
+
+    Ok(Answer::Int(0))
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn title(&self) -> &'static str {
+        "{title}"
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, ParseError> {
+        solve_part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::test_input;
+
+    #[test]
+    fn day{day}() {
+        let input = test_input({day});
+
+        assert_eq!(solve_part1(&input), Ok(Answer::Int(0)));
+        assert_eq!(solve_part2(&input), Ok(Answer::Int(0)));
+    }
+}
+"#;
+
+const BINARY: &str = r#"use std::process::ExitCode;
+
+use aoc2024::days::day{day}::Day{day};
+
+fn main() -> ExitCode {
+    aoc2024::cli::day_main(&Day{day})
+}
+"#;
+
+pub fn solver(day: u32, title: &str) -> String {
+    SOLVER
+        .replace("{day}", &day.to_string())
+        .replace("{title}", &title.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub fn binary(day: u32) -> String {
+    BINARY.replace("{day}", &day.to_string())
+}
+
+fn day_of(s: &str) -> Option<u32> {
+    let s = s.strip_prefix("day")?;
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+
+    s[..end].parse().ok()
+}
+
+/// Adds a day to the `days` module: its `mod` declaration, sorted like
+/// rustfmt does, and its entry in `DAYS`, in calendar order.
+pub fn register(mod_rs: &str, day: u32) -> Result<String, String> {
+    let module = format!("day{day}");
+    let mod_line = format!("pub mod {module};");
+    let entry_line = format!("    &{module}::Day{day},");
+
+    let mut lines: Vec<String> = mod_rs.lines().map(str::to_string).collect();
+
+    if lines.contains(&mod_line) {
+        return Err(format!("Day {day} is already registered"));
+    }
+
+    let mods: Vec<_> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod day"))
+        .collect();
+    let Some(&last) = mods.last() else {
+        return Err("No day module declaration found".to_string());
+    };
+
+    let mod_name = |l: &str| {
+        l.trim_start_matches("pub mod ")
+            .trim_end_matches(';')
+            .to_string()
+    };
+    let mod_idx = mods
+        .iter()
+        .copied()
+        .find(|&i| mod_name(&lines[i]) > module)
+        .unwrap_or(last + 1);
+    lines.insert(mod_idx, mod_line);
+
+    let entries: Vec<_> = (0..lines.len())
+        .filter(|&i| lines[i].trim_start().starts_with("&day"))
+        .collect();
+    let Some(&last) = entries.last() else {
+        return Err("No DAYS entry found".to_string());
+    };
+
+    let entry_idx = entries
+        .iter()
+        .copied()
+        .find(|&i| day_of(lines[i].trim_start().trim_start_matches('&')) > Some(day))
+        .unwrap_or(last + 1);
+    lines.insert(entry_idx, entry_line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the solver, its binary and empty puzzle and example inputs for
+/// `day`, and registers the solver. `root` is the repository root. The
+/// example is checked by the `examples` test once it is filled in and its
+/// answers are added to `answers.toml`.
+pub fn scaffold(root: &Path, day: u32, title: &str) -> Result<Vec<PathBuf>, String> {
+    let mod_rs = root.join("src/days/mod.rs");
+    let solver_path = root.join(format!("src/days/day{day}.rs"));
+    let binary_path = root.join(format!("src/bin/day{day}.rs"));
+    let input_dir = root.join(format!("inputs/day{day:02}"));
+
+    let write_err =
+        |path: &Path, e: std::io::Error| format!("Cannot write '{}': {e}", path.display());
+
+    let registry = fs::read_to_string(&mod_rs).map_err(|e| {
+        format!(
+            "Cannot read '{}', run from the repository root: {e}",
+            mod_rs.display()
+        )
+    })?;
+
+    for path in [&solver_path, &binary_path] {
+        if path.exists() {
+            return Err(format!("'{}' already exists", path.display()));
+        }
+    }
+
+    let registry = register(&registry, day)?;
+
+    // The solver and its binary are removed if they cannot be registered,
+    // rather than left behind
+    let mut created = vec![];
+    let remove = |created: &[PathBuf]| {
+        for path in created {
+            let _ = fs::remove_file(path);
+        }
+    };
+
+    for (path, contents) in [
+        (solver_path, solver(day, title)),
+        (binary_path, binary(day)),
+    ] {
+        if let Err(e) = fs::write(&path, contents) {
+            remove(&created);
+            return Err(write_err(&path, e));
+        }
+        created.push(path);
+    }
+
+    if let Err(e) = fs::write(&mod_rs, registry) {
+        remove(&created);
+        return Err(write_err(&mod_rs, e));
+    }
+
+    fs::create_dir_all(&input_dir).map_err(|e| write_err(&input_dir, e))?;
+
+    for name in ["input", "example"] {
+        let path = input_dir.join(format!("{name}.txt"));
+        if !path.exists() {
+            fs::write(&path, "").map_err(|e| write_err(&path, e))?;
+            created.push(path);
+        }
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register() {
+        const MOD_RS: &str = "use crate::Solution;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day13;
+pub mod day2;
+
+pub static DAYS: &[&dyn Solution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day10::Day10,
+    &day11::Day11,
+    &day13::Day13,
+];
+";
+
+        assert_eq!(
+            super::register(MOD_RS, 12).unwrap(),
+            "use crate::Solution;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day2;
+
+pub static DAYS: &[&dyn Solution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+];
+"
+        );
+
+        let registered = super::register(MOD_RS, 25).unwrap();
+        assert!(registered.contains("pub mod day2;\npub mod day25;\n"));
+        assert!(registered.contains("    &day13::Day13,\n    &day25::Day25,\n];"));
+
+        assert!(super::register(MOD_RS, 10).is_err());
+
+        let solver = solver(12, "Garden Groups");
+        assert!(solver.contains("const DAY: u32 = 12;"));
+        assert!(solver.contains("impl Solution for Day12 {"));
+        assert!(solver.contains("\"Garden Groups\""));
+        assert!(solver.contains("fn day12() {"));
+        assert!(!solver.contains("todo!"));
+
+        let solver = super::solver(12, r#"A "quoted" \ title"#);
+        assert!(solver.contains(r#""A \"quoted\" \\ title""#));
+    }
+}
@@ -0,0 +1,133 @@
+//! Tracing of what the solvers do, written to stderr.
+//!
+//! Tracing is off unless a level is set, with `-v` for `Info` and `-vv` for
+//! `Debug`. Events are attached to the spans opened on the current thread,
+//! such as the day and part being solved.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Instant;
+
+use crate::bench::format_duration;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    /// Spans and a few events per part.
+    Info = 1,
+    /// Every notable step of the solvers.
+    Debug = 2,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Info => f.pad("INFO"),
+            Level::Debug => f.pad("DEBUG"),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    static SPANS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+/// Sets the most detailed level traced, `None` turning tracing off.
+pub fn set_level(level: Option<Level>) {
+    LEVEL.store(level.map_or(0, |l| l as u8), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+fn format_event(level: Level, spans: &[String], message: fmt::Arguments) -> String {
+    format!("{level:>5} [{}] {message}", spans.join(" > "))
+}
+
+/// Writes an event, whatever the level. Use the `info!` and `debug!` macros
+/// instead, which skip formatting when the level is not traced.
+pub fn emit(level: Level, message: fmt::Arguments) {
+    let line = SPANS.with(|spans| format_event(level, &spans.borrow(), message));
+
+    eprintln!("{line}");
+}
+
+/// Traces an event at the `Info` level.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Info) {
+            $crate::trace::emit($crate::trace::Level::Info, format_args!($($arg)*));
+        }
+    };
+}
+
+/// Traces an event at the `Debug` level.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Debug) {
+            $crate::trace::emit($crate::trace::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+/// A span, closed when dropped.
+pub struct Span {
+    start: Instant,
+}
+
+/// Opens a span on the current thread. Its closing is traced at the `Info`
+/// level, along with its duration.
+pub fn span(name: impl Into<String>) -> Span {
+    SPANS.with(|spans| spans.borrow_mut().push(name.into()));
+
+    Span {
+        start: Instant::now(),
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        crate::info!("done in {}", format_duration(self.start.elapsed()));
+
+        SPANS.with(|spans| spans.borrow_mut().pop());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans() -> Vec<String> {
+        SPANS.with(|spans| spans.borrow().clone())
+    }
+
+    #[test]
+    fn trace() {
+        {
+            let _day = span("day 5");
+            let _part = span("part 2");
+            assert_eq!(spans(), vec!["day 5", "part 2"]);
+        }
+        assert!(spans().is_empty());
+
+        assert_eq!(
+            format_event(
+                Level::Debug,
+                &["day 5".to_string(), "part 2".to_string()],
+                format_args!("swap {} and {}", 97, 75)
+            ),
+            "DEBUG [day 5 > part 2] swap 97 and 75"
+        );
+        assert_eq!(
+            format_event(Level::Info, &[], format_args!("tree found")),
+            " INFO [] tree found"
+        );
+
+        assert!(Level::Debug > Level::Info);
+    }
+}
@@ -0,0 +1,262 @@
+//! Checks the solvers against a file of known answers.
+//!
+//! The answers file is a small subset of TOML, with one table per day and
+//! input name:
+//!
+//! ```toml
+//! [day1.input]
+//! part1 = 1258579
+//! part2 = "23981443"
+//! ```
+//!
+//! Other keys are parameters of the input, passed to its solver, such as
+//! the `width` and `height` of the room of the day 14 example.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+
+use crate::input::Inputs;
+use crate::report;
+use crate::{Params, Solution};
+
+/// Answers file used when none is given.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Entry {
+    parts: [Option<String>; 2],
+    params: Params,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(u32, String), Entry>,
+}
+
+fn parse_section(s: &str) -> Option<(u32, String)> {
+    let s = s.strip_prefix('[')?.strip_suffix(']')?;
+    let (day, name) = s.trim().split_once('.')?;
+    let day = day.strip_prefix("day")?.parse().ok()?;
+
+    (!name.is_empty()).then(|| (day, name.to_string()))
+}
+
+fn parse_value(s: &str) -> Option<String> {
+    if let Some(s) = s.strip_prefix('"') {
+        return s.strip_suffix('"').map(str::to_string);
+    }
+
+    s.parse::<i128>().ok().map(|v| v.to_string())
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        let mut section = None;
+
+        for (idx, l) in s.lines().enumerate() {
+            let err = |message: &str| format!("line {}: {message}: '{l}'", idx + 1);
+
+            let l = l.split_once('#').map_or(l, |(l, _)| l).trim();
+            if l.is_empty() {
+                continue;
+            }
+
+            if l.starts_with('[') {
+                let key = parse_section(l).ok_or_else(|| err("expected '[dayN.name]'"))?;
+                answers.entries.entry(key.clone()).or_default();
+                section = Some(key);
+                continue;
+            }
+
+            let key = section
+                .as_ref()
+                .ok_or_else(|| err("answer outside of a [dayN.name] table"))?;
+
+            let (name, value) = l.split_once('=').ok_or_else(|| err("expected '='"))?;
+            let value = parse_value(value.trim()).ok_or_else(|| err("invalid value"))?;
+            let entry = answers.entries.get_mut(key).unwrap();
+
+            match name.trim() {
+                "part1" => entry.parts[0] = Some(value),
+                "part2" => entry.parts[1] = Some(value),
+                name if name.starts_with("part") => return Err(err("expected 'part1' or 'part2'")),
+                name => {
+                    entry.params.insert(name.to_string(), value);
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read '{}': {e}", path.display()))?;
+
+        Self::parse(&s).map_err(|e| format!("{}, {e}", path.display()))
+    }
+
+    /// Expected answer of a part, starting at 1.
+    pub fn get(&self, day: u32, name: &str, part: u32) -> Option<&str> {
+        let entry = self.entries.get(&(day, name.to_string()))?;
+        entry.parts[part as usize - 1].as_deref()
+    }
+
+    /// Parameters of an input, empty when it has none.
+    pub fn params(&self, day: u32, name: &str) -> Params {
+        self.entries
+            .get(&(day, name.to_string()))
+            .map(|entry| entry.params.clone())
+            .unwrap_or_default()
+    }
+
+    /// Input names having answers for a day.
+    pub fn names(&self, day: u32) -> impl Iterator<Item = &str> {
+        self.entries
+            .keys()
+            .filter(move |(d, _)| *d == day)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Days having answers.
+    pub fn days(&self) -> BTreeSet<u32> {
+        self.entries.keys().map(|(day, _)| *day).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(String),
+    Missing(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Check {
+    pub day: u32,
+    pub name: String,
+    pub part: u32,
+    pub status: Status,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {:>2}  {:<12}  Part {}  ",
+            self.day, self.name, self.part
+        )?;
+
+        match &self.status {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL     expected {expected}, got {actual}")
+            }
+            Status::Error(e) => write!(f, "FAIL     {e}"),
+            Status::Missing(reason) => write!(f, "MISSING  {reason}"),
+        }
+    }
+}
+
+fn check(
+    solution: &dyn Solution,
+    input: &str,
+    params: &Params,
+    part: u32,
+    expected: Option<&str>,
+) -> Status {
+    let actual = match report::run_part(solution, part, input, params) {
+        Ok(report) => report.answer.to_string(),
+        Err(e) => return Status::Error(e.to_string()),
+    };
+
+    match expected {
+        Some(expected) if expected == actual => Status::Pass,
+        Some(expected) => Status::Fail {
+            expected: expected.to_string(),
+            actual,
+        },
+        None => Status::Missing(format!("no answer recorded, got {actual}")),
+    }
+}
+
+/// Runs every input of a day, whether it has answers or is only present in
+/// the inputs directory.
+pub fn verify_day(
+    day: u32,
+    solution: Option<&dyn Solution>,
+    answers: &Answers,
+    inputs: &Inputs,
+) -> Vec<Check> {
+    let mut names: BTreeSet<String> = answers.names(day).map(str::to_string).collect();
+    names.extend(inputs.names(day));
+
+    let mut checks = vec![];
+
+    for name in names {
+        let input = inputs.read_named(day, &name);
+        let params = answers.params(day, &name);
+
+        for part in 1..=2 {
+            let expected = answers.get(day, &name, part);
+
+            let status = match (solution, &input) {
+                (None, _) => Status::Missing("day not solved".to_string()),
+                (_, Err(e)) => Status::Missing(e.to_string()),
+                (Some(solution), Ok(input)) => check(solution, input, &params, part, expected),
+            };
+
+            checks.push(Check {
+                day,
+                name: name.clone(),
+                part,
+                status,
+            });
+        }
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers() {
+        let answers = Answers::parse(
+            "# Known answers
+[day1.input]
+part1 = 1258579
+part2 = \"23981443\" # as a string
+
+[day6.alice]
+part2 = 6
+width = 11
+",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, "input", 1), Some("1258579"));
+        assert_eq!(answers.get(1, "input", 2), Some("23981443"));
+        assert_eq!(answers.get(6, "alice", 1), None);
+        assert_eq!(answers.get(6, "bob", 2), None);
+        assert_eq!(answers.names(6).collect::<Vec<_>>(), vec!["alice"]);
+        assert_eq!(
+            answers.params(6, "alice"),
+            Params::from([("width".to_string(), "11".to_string())])
+        );
+        assert!(answers.params(1, "input").is_empty());
+        assert_eq!(answers.days(), BTreeSet::from([1, 6]));
+
+        assert_eq!(
+            Answers::parse("part1 = 1"),
+            Err("line 1: answer outside of a [dayN.name] table: 'part1 = 1'".to_string())
+        );
+        assert!(Answers::parse("[day1.input]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day1]").is_err());
+    }
+}
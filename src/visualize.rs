@@ -0,0 +1,293 @@
+//! Frames of the simulations, and their animation in a terminal.
+
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::render::{self, Rgb};
+
+/// State of a simulation at one point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    /// Short description shown under the grid.
+    pub caption: String,
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.grid.height() {
+            let row: String = self.grid.row(y).into_iter().flatten().collect();
+            writeln!(f, "{row}")?;
+        }
+
+        write!(f, "{}", self.caption)
+    }
+}
+
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+/// A solver whose puzzle is a simulation that can be watched.
+pub trait Visualize {
+    /// Frames of the simulation run on `input`, in order.
+    fn frames<'a>(&self, input: &'a str) -> Result<Frames<'a>, ParseError>;
+
+    /// Colour of a tile when the frames are exported as images.
+    fn color(&self, c: char) -> Rgb {
+        render::color(c)
+    }
+}
+
+/// Frames to show, as `start..end` where both bounds are optional.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameRange {
+    pub start: usize,
+    pub end: Option<usize>,
+}
+
+impl FrameRange {
+    pub fn parse(s: &str) -> Option<Self> {
+        let (start, end) = s.split_once("..")?;
+
+        let start = match start {
+            "" => 0,
+            s => s.parse().ok()?,
+        };
+        let end = match end {
+            "" => None,
+            s => Some(s.parse().ok()?),
+        };
+
+        Some(Self { start, end })
+    }
+
+    /// Keeps the selected frames, along with their index.
+    pub fn select<'a>(self, frames: Frames<'a>) -> impl Iterator<Item = (usize, Frame)> + 'a {
+        frames
+            .enumerate()
+            .skip(self.start)
+            .take_while(move |(idx, _)| self.end.is_none_or(|end| *idx < end))
+    }
+}
+
+/// Keys understood while playing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Control {
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    pub fn from_key(key: u8) -> Option<Self> {
+        match key {
+            b' ' | b'p' => Some(Self::TogglePause),
+            b'n' => Some(Self::Step),
+            b'+' => Some(Self::Faster),
+            b'-' => Some(Self::Slower),
+            b'q' => Some(Self::Quit),
+            _ => None,
+        }
+    }
+}
+
+const CONTROLS_HELP: &str = "space: pause, n: step, +/-: speed, q: quit";
+
+pub struct Player {
+    pub fps: f64,
+    pub paused: bool,
+}
+
+impl Player {
+    pub fn new(fps: f64) -> Self {
+        Self { fps, paused: false }
+    }
+
+    fn draw(&self, out: &mut impl Write, idx: usize, frame: &Frame) -> io::Result<()> {
+        let state = if self.paused { "  [paused]" } else { "" };
+
+        writeln!(out, "\x1b[H\x1b[2J{frame}")?;
+        writeln!(
+            out,
+            "Frame {idx}, {} fps{state}  ({CONTROLS_HELP})",
+            self.fps
+        )?;
+        out.flush()
+    }
+
+    /// Shows the frames one after the other, until the last one or until
+    /// asked to quit.
+    pub fn play(
+        &mut self,
+        frames: impl Iterator<Item = (usize, Frame)>,
+        controls: &Receiver<Control>,
+        out: &mut impl Write,
+    ) -> io::Result<()> {
+        for (idx, frame) in frames {
+            self.draw(out, idx, &frame)?;
+
+            let mut deadline = Instant::now() + Duration::from_secs_f64(1.0 / self.fps);
+
+            loop {
+                let control = if self.paused {
+                    // Nothing can resume the animation once the controls are
+                    // gone
+                    controls.recv().ok().or(Some(Control::TogglePause))
+                } else {
+                    match controls.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    {
+                        Ok(control) => Some(control),
+                        Err(RecvTimeoutError::Timeout) => None,
+                        Err(RecvTimeoutError::Disconnected) => {
+                            std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+                            None
+                        }
+                    }
+                };
+
+                match control {
+                    None => break,
+                    Some(Control::Quit) => return Ok(()),
+                    Some(Control::Step) if self.paused => break,
+                    Some(Control::Step) => {}
+                    Some(Control::TogglePause) => {
+                        self.paused = !self.paused;
+                        deadline = Instant::now() + Duration::from_secs_f64(1.0 / self.fps);
+                    }
+                    Some(Control::Faster) => self.fps = (self.fps * 2.0).min(1000.0),
+                    Some(Control::Slower) => self.fps = (self.fps / 2.0).max(0.25),
+                }
+
+                self.draw(out, idx, &frame)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Puts the terminal in a mode where keys are read without waiting for
+/// Enter, and restores it when dropped. Without a terminal, keys are only
+/// seen once Enter is pressed.
+pub struct RawTerminal {
+    saved: Option<String>,
+}
+
+impl RawTerminal {
+    pub fn enable() -> Self {
+        let saved = stty(&["-g"]);
+        if saved.is_some() {
+            stty(&["-icanon", "-echo", "min", "1"]);
+        }
+
+        Self { saved }
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            stty(&[saved]);
+        }
+    }
+}
+
+/// Reads the controls from stdin, in a background thread.
+pub fn stdin_controls() -> Receiver<Control> {
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        for key in io::stdin().lock().bytes() {
+            let Ok(key) = key else {
+                break;
+            };
+
+            if let Some(control) = Control::from_key(key) {
+                if tx.send(control).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn player() {
+        assert_eq!(
+            FrameRange::parse("10..20"),
+            Some(FrameRange {
+                start: 10,
+                end: Some(20)
+            })
+        );
+        assert_eq!(FrameRange::parse(".."), Some(FrameRange::default()));
+        assert_eq!(FrameRange::parse("5"), None);
+
+        let frames = || -> Frames {
+            Box::new((0..5).map(|i| Frame {
+                grid: Grid::new(2, 1, char::from(b'a' + i)),
+                caption: format!("step {i}"),
+            }))
+        };
+
+        let selected: Vec<_> = FrameRange::parse("1..3")
+            .unwrap()
+            .select(frames())
+            .map(|(idx, f)| (idx, f.to_string()))
+            .collect();
+        assert_eq!(
+            selected,
+            vec![(1, "bb\nstep 1".to_string()), (2, "cc\nstep 2".to_string())]
+        );
+
+        let (tx, rx) = mpsc::channel();
+        let mut out = vec![];
+        Player::new(1000.0)
+            .play(FrameRange::default().select(frames()), &rx, &mut out)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap().matches("\x1b[2J").count(),
+            5
+        );
+
+        // Pausing redraws, stepping moves on, quitting stops
+        for control in [Control::TogglePause, Control::Step, Control::Quit] {
+            tx.send(control).unwrap();
+        }
+        let mut out = vec![];
+        let mut player = Player::new(1.0);
+        player
+            .play(FrameRange::default().select(frames()), &rx, &mut out)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[2J").count(), 3);
+        assert!(out.contains("Frame 1, 1 fps  [paused]"));
+        assert!(!out.contains("Frame 2"));
+    }
+}